@@ -0,0 +1,199 @@
+extern crate rust_graph;
+extern crate time;
+
+use std::collections::VecDeque;
+
+use time::{Duration, PreciseTime};
+
+use rust_graph::graph_gen::RMatGenerator;
+use rust_graph::prelude::*;
+use rust_graph::UnStaticGraphConverter;
+use rust_graph::{UnGraphMap, UnStaticGraph};
+
+/// Compare the boxed iterators of `GraphTrait` with the statically dispatched ones of
+/// `GraphIterTrait`, on an undirected Graph500 graph of the given scale (16 by default),
+/// and in converting a graph map of it into a static graph.
+fn main() {
+    let scale = std::env::args()
+        .nth(1)
+        .map_or(16, |arg| arg.parse().expect("scale must be a number"));
+
+    println!("Generating a Graph500 graph of scale {}", scale);
+    let g: UnStaticGraph<Void> = RMatGenerator::graph500(scale, 16).generate();
+    println!("{} nodes, {} edges", g.node_count(), g.edge_count());
+    println!("------------------------------");
+
+    compare(
+        "Degree sum",
+        || degree_sum_boxed(&g),
+        || degree_sum_static(&g),
+    );
+    compare("BFS", || bfs_boxed(&g), || bfs_static(&g));
+    compare("Triangles", || triangles_boxed(&g), || triangles_static(&g));
+
+    let converter = UnStaticGraphConverter::new(to_graphmap(&g), false, false);
+    compare(
+        "Conversion",
+        || edge_vec_boxed(converter.get_graphmap()),
+        || converter.convert().get_edge_vec().len(),
+    );
+}
+
+fn to_graphmap(g: &UnStaticGraph<Void>) -> UnGraphMap<Void> {
+    let mut map = UnGraphMap::new();
+
+    for v in g.node_indices() {
+        map.add_node(v, None);
+    }
+
+    for (s, t) in g.edge_indices() {
+        map.add_edge(s, t, None);
+    }
+
+    map
+}
+
+fn compare<F: Fn() -> usize, G: Fn() -> usize>(name: &str, boxed: F, fast: G) {
+    let (boxed_result, boxed_time) = timed(boxed);
+    let (static_result, static_time) = timed(fast);
+
+    assert_eq!(boxed_result, static_result);
+
+    println!("{}: {}", name, boxed_result);
+    println!("- boxed:  {} seconds", boxed_time);
+    println!("- static: {} seconds", static_time);
+}
+
+fn timed<F: Fn() -> usize>(f: F) -> (usize, Duration) {
+    let start = PreciseTime::now();
+    let result = f();
+    let end = PreciseTime::now();
+
+    (result, start.to(end))
+}
+
+fn degree_sum_boxed<G: GraphTrait<DefaultId>>(g: &G) -> usize {
+    g.node_indices().map(|v| g.neighbors_iter(v).count()).sum()
+}
+
+fn degree_sum_static<G: for<'a> GraphIterTrait<'a, DefaultId>>(g: &G) -> usize {
+    g.iter_node_indices()
+        .map(|v| g.iter_neighbors(v).count())
+        .sum()
+}
+
+// The number of nodes reachable from node 0, if any.
+fn bfs_boxed<G: GraphTrait<DefaultId>>(g: &G) -> usize {
+    if !g.has_node(0) {
+        return 0;
+    }
+
+    let mut visited = vec![false; g.node_count()];
+    let mut queue = VecDeque::new();
+    let mut count = 0;
+
+    visited[0] = true;
+    queue.push_back(0);
+
+    while let Some(v) = queue.pop_front() {
+        count += 1;
+
+        for u in g.neighbors_iter(v) {
+            if !visited[u.id()] {
+                visited[u.id()] = true;
+                queue.push_back(u);
+            }
+        }
+    }
+
+    count
+}
+
+fn bfs_static<G: for<'a> GraphIterTrait<'a, DefaultId>>(g: &G) -> usize {
+    if !g.has_node(0) {
+        return 0;
+    }
+
+    let mut visited = vec![false; g.node_count()];
+    let mut queue = VecDeque::new();
+    let mut count = 0;
+
+    visited[0] = true;
+    queue.push_back(0);
+
+    while let Some(v) = queue.pop_front() {
+        count += 1;
+
+        for u in g.iter_neighbors(v) {
+            if !visited[u.id()] {
+                visited[u.id()] = true;
+                queue.push_back(u);
+            }
+        }
+    }
+
+    count
+}
+
+// Count every triangle `u < v < w` once, by merging sorted neighbor lists.
+fn triangles_boxed<G: GraphTrait<DefaultId>>(g: &G) -> usize {
+    let mut count = 0;
+
+    for (u, v) in g.edge_indices() {
+        count += count_common(
+            g.neighbors_iter(u).filter(|&w| w > v),
+            g.neighbors_iter(v).filter(|&w| w > v),
+        );
+    }
+
+    count
+}
+
+fn triangles_static<G: for<'a> GraphIterTrait<'a, DefaultId>>(g: &G) -> usize {
+    let mut count = 0;
+
+    for (u, v) in g.iter_edge_indices() {
+        count += count_common(
+            g.iter_neighbors(u).filter(|&w| w > v),
+            g.iter_neighbors(v).filter(|&w| w > v),
+        );
+    }
+
+    count
+}
+
+// The sorted neighbors of every node, as laid out by the converter, which collects them
+// with `GraphIterTrait`.
+fn edge_vec_boxed<G: GraphTrait<DefaultId>>(g: &G) -> usize {
+    let mut edges = Vec::new();
+
+    for v in 0..g.node_count() {
+        let mut neighbors: Vec<_> = g.neighbors_iter(DefaultId::new(v)).collect();
+        neighbors.sort_unstable();
+        edges.extend(neighbors);
+    }
+
+    edges.len()
+}
+
+fn count_common<I: Iterator<Item = DefaultId>, J: Iterator<Item = DefaultId>>(
+    mut a: I,
+    mut b: J,
+) -> usize {
+    let mut count = 0;
+    let (mut x, mut y) = (a.next(), b.next());
+
+    while let (Some(i), Some(j)) = (x, y) {
+        if i < j {
+            x = a.next();
+        } else if i > j {
+            y = b.next();
+        } else {
+            count += 1;
+            x = a.next();
+            y = b.next();
+        }
+    }
+
+    count
+}
@@ -5,8 +5,8 @@ use std::path::Path;
 
 use time::PreciseTime;
 
+use rust_graph::io::ldbc::Scheme;
 use rust_graph::io::serde::{Serialize, Serializer};
-use rust_graph::io::*;
 use rust_graph::prelude::*;
 
 fn main() {
@@ -18,7 +18,18 @@ fn main() {
     let start = PreciseTime::now();
 
     println!("Loading {:?}", &ldbc_dir);
-    let g = read_ldbc_from_path::<u32, Undirected, _>(ldbc_dir);
+    let data = Scheme::init().load::<u32, Undirected, _>(ldbc_dir).unwrap();
+
+    for file in data.get_report().get_files() {
+        println!(
+            "{:?}: {} records, {} unresolved.",
+            file.get_path(),
+            file.num_of_records(),
+            file.num_of_unresolved()
+        );
+    }
+
+    let g = data.into_graph();
     let num_of_nodes = g.node_count();
     let num_of_edges = g.edge_count();
 
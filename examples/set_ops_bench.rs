@@ -0,0 +1,109 @@
+extern crate rand;
+extern crate rust_graph;
+extern crate time;
+
+use rand::Rng;
+use time::{Duration, PreciseTime};
+
+use rust_graph::graph_gen::{seeded_rng, RMatGenerator};
+use rust_graph::prelude::*;
+use rust_graph::set_ops::*;
+use rust_graph::UnStaticGraph;
+
+type CountKernel = fn(&[DefaultId], &[DefaultId]) -> usize;
+
+const KERNELS: [(&str, CountKernel); 4] = [
+    ("merge", intersect_count_merge),
+    ("galloping", intersect_count_galloping),
+    ("block", intersect_count_block),
+    ("hybrid", intersect_count),
+];
+
+/// Compare the intersection kernels of `set_ops` by counting the triangles of an undirected
+/// Graph500 graph of the given scale (16 by default), and by intersecting random sets of
+/// skewed sizes.
+fn main() {
+    let scale = std::env::args()
+        .nth(1)
+        .map_or(16, |arg| arg.parse().expect("scale must be a number"));
+
+    println!("Generating a Graph500 graph of scale {}", scale);
+    let g: UnStaticGraph<Void> = RMatGenerator::graph500(scale, 16).generate();
+    println!("{} nodes, {} edges", g.node_count(), g.edge_count());
+    println!("------------------------------");
+
+    compare("Triangles", |kernel| triangles(&g, kernel));
+
+    let mut rng = seeded_rng(0);
+    for &ratio in [1, 8, 64, 512].iter() {
+        let pairs: Vec<_> = (0..100)
+            .map(|_| {
+                let large = random_set(&mut rng, 1 << 20, 64);
+                let small = random_set(&mut rng, 1 << 20, 64 * ratio);
+                (small, large)
+            })
+            .collect();
+
+        compare(&format!("Random sets of size ratio {}", ratio), |kernel| {
+            pairs
+                .iter()
+                .map(|(small, large)| kernel(small, large))
+                .sum()
+        });
+    }
+}
+
+fn compare<F: Fn(CountKernel) -> usize>(name: &str, f: F) {
+    let results: Vec<_> = KERNELS
+        .iter()
+        .map(|&(kernel_name, kernel)| (kernel_name, timed(|| f(kernel))))
+        .collect();
+
+    let expected = (results[0].1).0;
+    println!("{}: {}", name, expected);
+
+    for (kernel_name, (result, duration)) in results {
+        assert_eq!(result, expected);
+        println!("- {}: {} seconds", kernel_name, duration);
+    }
+}
+
+fn timed<F: Fn() -> usize>(f: F) -> (usize, Duration) {
+    let start = PreciseTime::now();
+    let result = f();
+    let end = PreciseTime::now();
+
+    (result, start.to(end))
+}
+
+// Count every triangle `u < v < w` once, by intersecting the neighbors of `u` and `v`
+// that are larger than `v`.
+fn triangles(g: &UnStaticGraph<Void>, kernel: CountKernel) -> usize {
+    let edge_vec = g.get_edge_vec();
+    let mut count = 0;
+
+    for u in g.node_indices() {
+        let u_neighbors = edge_vec.neighbors(u);
+
+        for (i, &v) in u_neighbors.iter().enumerate() {
+            if v <= u {
+                continue;
+            }
+
+            let v_neighbors = edge_vec.neighbors(v);
+            let start = v_neighbors.partition_point(|&w| w <= v);
+
+            count += kernel(&u_neighbors[i + 1..], &v_neighbors[start..]);
+        }
+    }
+
+    count
+}
+
+// A sorted set of ids below `max`, each of which is in the set with probability `1 / gap`.
+fn random_set<R: Rng>(rng: &mut R, max: usize, gap: usize) -> Vec<DefaultId> {
+    (0..max)
+        .filter(|_| rng.gen_range(0, gap) == 0)
+        .map(DefaultId::new)
+        .collect()
+}
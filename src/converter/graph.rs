@@ -1,13 +1,17 @@
 use std::hash::Hash;
 
+use error::{GraphError, Result};
+
 use generic::Iter;
-use generic::node::NodeMapTrait;
 use generic::{DefaultId, IdType};
 use generic::{DefaultTy, Directed, GraphType, Undirected};
-use generic::{DiGraphTrait, GraphLabelTrait, GraphTrait};
+use generic::{DiGraphIterTrait, GraphIterTrait};
+use generic::{GraphLabelTrait, GraphTrait};
 use generic::{EdgeTrait, NodeTrait};
 use generic::{MapTrait, MutMapTrait};
 
+use converter::NodeOrder;
+
 use graph_impl::static_graph::EdgeVec;
 use graph_impl::{TypedDiStaticGraph, TypedGraphMap, TypedUnStaticGraph};
 
@@ -30,7 +34,7 @@ where
     Ty: GraphType,
 {
     graphmap: TypedGraphMap<Id, NL, EL, Ty>,
-    reorder_node_id: bool,
+    node_order: NodeOrder,
     reorder_label_id: bool,
     node_id_map: Option<SetMap<Id>>,
     node_label_id_map: Option<SetMap<Id>>,
@@ -46,14 +50,52 @@ where
     EL: Hash + Eq,
     Ty: GraphType,
 {
+    /// Reorder the nodes by `NodeOrder::DegreeAscending` if `reorder_node_id`, and keep their
+    /// ids otherwise.
     pub fn new(
         g: TypedGraphMap<Id, NL, EL, Ty>,
         reorder_node_id: bool,
         reorder_label_id: bool,
     ) -> Self {
+        Self::try_new(g, reorder_node_id, reorder_label_id).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `new`, but return an error instead of panicking if the node ids are not to be
+    /// reordered and do not range over `0 .. node_count`.
+    pub fn try_new(
+        g: TypedGraphMap<Id, NL, EL, Ty>,
+        reorder_node_id: bool,
+        reorder_label_id: bool,
+    ) -> Result<Self> {
+        let node_order = if reorder_node_id {
+            NodeOrder::DegreeAscending
+        } else {
+            NodeOrder::Original
+        };
+
+        Self::try_with_node_order(g, node_order, reorder_label_id)
+    }
+
+    /// Reorder the nodes by `node_order`.
+    pub fn with_node_order(
+        g: TypedGraphMap<Id, NL, EL, Ty>,
+        node_order: NodeOrder,
+        reorder_label_id: bool,
+    ) -> Self {
+        Self::try_with_node_order(g, node_order, reorder_label_id)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `with_node_order`, but return an error instead of panicking if the node ids
+    /// are kept by `NodeOrder::Original` and do not range over `0 .. node_count`.
+    pub fn try_with_node_order(
+        g: TypedGraphMap<Id, NL, EL, Ty>,
+        node_order: NodeOrder,
+        reorder_label_id: bool,
+    ) -> Result<Self> {
         let mut converter = TypedStaticGraphConverter {
             graphmap: g,
-            reorder_node_id,
+            node_order,
             reorder_label_id,
             node_id_map: None,
             node_label_id_map: None,
@@ -62,13 +104,19 @@ where
             edge_label_map: None,
         };
 
-        if reorder_node_id {
-            let node_id_map = converter.reorder_node_id_map();
+        if node_order != NodeOrder::Original {
+            let node_id_map = node_order.order(converter.get_graphmap()).into_iter().collect();
             converter.set_node_id_map(Some(node_id_map));
         } else {
-            let max_node_id = converter.get_graphmap().node_indices().max().unwrap().id();
+            let max_node_id = converter.get_graphmap().node_indices().max();
             let num_of_nodes = converter.get_graphmap().node_count();
-            assert_eq!(max_node_id + 1, num_of_nodes);
+
+            if max_node_id.map_or(0, |id| id.id() + 1) != num_of_nodes {
+                return Err(GraphError::InvalidArgument(format!(
+                    "The node ids must range over 0 .. {} if not reordered.",
+                    num_of_nodes
+                )));
+            }
         }
 
         if reorder_label_id {
@@ -79,7 +127,7 @@ where
             converter.set_edge_label_id_map(Some(edge_label_id_map));
         }
 
-        converter
+        Ok(converter)
     }
 
     //    pub fn with_node_label_map(mut self, node_label_map: SetMap<NL>) -> Self {
@@ -110,6 +158,10 @@ where
         self.graphmap = TypedGraphMap::new();
     }
 
+    pub fn get_node_order(&self) -> NodeOrder {
+        self.node_order
+    }
+
     pub fn get_node_id_map(&self) -> &Option<SetMap<Id>> {
         &self.node_id_map
     }
@@ -177,18 +229,6 @@ where
         self.edge_label_map = edge_label_map;
     }
 
-    /// Map node id to a continuous range (sort by degree)
-    fn reorder_node_id_map(&self) -> SetMap<Id> {
-        let mut node_degree: Vec<_> = self.get_graphmap()
-            .nodes()
-            .map(|n| n.unwrap_nodemap())
-            .map(|n| (n.get_id(), n.degree()))
-            .collect();
-        node_degree.sort_unstable_by_key(|&(_, d)| d);
-
-        node_degree.into_iter().map(|(n, _)| n).collect()
-    }
-
     /// Re-assign node label id sorted by its frequency
     fn reorder_node_label_id_map(&self) -> SetMap<Id> {
         let mut label_counter: Vec<_> = self.get_graphmap()
@@ -274,10 +314,10 @@ where
             offset_vec.push(offset);
 
             let mut neighbors: Vec<_> = match self.get_node_id_map() {
-                Some(map) => g.neighbors_iter(node_id)
+                Some(map) => g.iter_neighbors(node_id)
                     .map(|i| Id::new(map.find_index(&i).unwrap()))
                     .collect(),
-                None => g.neighbors_iter(node_id).collect(),
+                None => g.iter_neighbors(node_id).collect(),
             };
 
             neighbors.sort_unstable();
@@ -379,10 +419,11 @@ where
         )
     }
 
-    /// Convert in-edges into `EdgeVec` (edge labels will be ignored)
+    /// Convert in-edges into `EdgeVec`, with the labels of the corresponding edges.
     fn get_in_edge_vec(&self) -> EdgeVec<Id> {
         let g = self.get_graphmap();
 
+        let has_edge_label = g.edge_labels().next().is_some();
         let offset_len = g.node_count() + 1;
         let edge_len = g.edge_count();
 
@@ -390,14 +431,20 @@ where
         let mut offset_vec = Vec::with_capacity(offset_len);
         let mut edge_vec = Vec::with_capacity(edge_len);
 
+        let mut edge_labels = if has_edge_label {
+            Some(Vec::with_capacity(edge_len))
+        } else {
+            None
+        };
+
         for node_id in self.ids() {
             offset_vec.push(offset);
 
             let mut neighbors: Vec<_> = match self.get_node_id_map() {
-                Some(map) => g.in_neighbors_iter(node_id)
+                Some(map) => g.iter_in_neighbors(node_id)
                     .map(|i| Id::new(map.find_index(&i).unwrap()))
                     .collect(),
-                None => g.neighbors_iter(node_id).collect(),
+                None => g.iter_in_neighbors(node_id).collect(),
             };
 
             neighbors.sort_unstable();
@@ -405,12 +452,24 @@ where
 
             for neighbor in neighbors {
                 edge_vec.push(neighbor);
+
+                if let Some(ref mut labels) = edge_labels {
+                    let original_node = self.get_original_node_id(neighbor);
+
+                    labels.push(match g.get_edge(original_node, node_id).get_label_id() {
+                        Some(label) => self.find_new_edge_label_id(label),
+                        None => Id::max_value(),
+                    });
+                }
             }
         }
 
         offset_vec.push(edge_len);
 
-        EdgeVec::new(offset_vec, edge_vec)
+        match edge_labels {
+            Some(labels) => EdgeVec::with_labels(offset_vec, edge_vec, labels),
+            None => EdgeVec::new(offset_vec, edge_vec),
+        }
     }
 }
 
@@ -1,5 +1,7 @@
 pub mod graph;
+pub mod node_order;
 
 pub use converter::graph::{DiStaticGraphConverter, StaticGraphConverter, UnStaticGraphConverter};
 pub use converter::graph::{TypedDiStaticGraphConverter, TypedStaticGraphConverter,
                            TypedUnStaticGraphConverter};
+pub use converter::node_order::NodeOrder;
@@ -0,0 +1,250 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use rand::Rng;
+
+use generic::node::NodeMapTrait;
+use generic::{GraphTrait, GraphType, IdType, NodeTrait};
+use graph_gen::seeded_rng;
+use graph_impl::TypedGraphMap;
+
+/// How the nodes of a graph are assigned the continuous ids `0 .. node_count` when it is
+/// converted into a static graph. The orderings by connectivity ignore edge directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NodeOrder {
+    /// Keep the original ids, which must range over `0 .. node_count`.
+    Original,
+    /// By degree, ascending.
+    DegreeAscending,
+    /// By degree, descending, so that the hubs come first.
+    DegreeDescending,
+    /// A breadth-first search from the smallest unvisited id, visiting neighbors by id.
+    Bfs,
+    /// Reverse Cuthill–McKee: a breadth-first search from an unvisited node of minimum
+    /// degree, visiting neighbors by degree, reversed. It reduces the bandwidth of the
+    /// adjacency matrix.
+    ReverseCuthillMcKee,
+    /// The greedy ordering of Gorder: the next node is the one with the most neighbors and
+    /// common neighbors among the last `window` nodes placed, so that the nodes accessed
+    /// together have close ids.
+    Gorder { window: usize },
+    /// Grouped by label, and by degree ascending within a group. The nodes of no label come
+    /// last.
+    LabelGrouped,
+    /// A random permutation, seeded for reproducibility.
+    Random(u64),
+}
+
+impl NodeOrder {
+    /// The nodes of `g` in this order.
+    pub fn order<Id, NL, EL, Ty>(&self, g: &TypedGraphMap<Id, NL, EL, Ty>) -> Vec<Id>
+    where
+        Id: IdType,
+        NL: Hash + Eq,
+        EL: Hash + Eq,
+        Ty: GraphType,
+    {
+        let mut ids: Vec<Id> = g.node_indices().collect();
+        ids.sort_unstable();
+
+        let degree = |id: Id| g.get_node(id).unwrap_nodemap().degree();
+
+        match *self {
+            NodeOrder::Original => ids,
+            NodeOrder::DegreeAscending => {
+                ids.sort_by_key(|&id| degree(id));
+                ids
+            }
+            NodeOrder::DegreeDescending => {
+                ids.sort_by_key(|&id| Reverse(degree(id)));
+                ids
+            }
+            NodeOrder::LabelGrouped => {
+                ids.sort_by_key(|&id| {
+                    let label = g.get_node(id).unwrap_nodemap().get_label_id();
+                    (label.is_none(), label, degree(id))
+                });
+                ids
+            }
+            NodeOrder::Random(seed) => {
+                seeded_rng(seed).shuffle(&mut ids);
+                ids
+            }
+            NodeOrder::Bfs => {
+                let adjacency = adjacency(g, &ids);
+                let starts: Vec<_> = (0..ids.len()).collect();
+
+                bfs(&adjacency, &starts, |v| v)
+                    .into_iter()
+                    .map(|v| ids[v])
+                    .collect()
+            }
+            NodeOrder::ReverseCuthillMcKee => {
+                let adjacency = adjacency(g, &ids);
+                let mut starts: Vec<_> = (0..ids.len()).collect();
+                starts.sort_by_key(|&v| adjacency[v].len());
+
+                bfs(&adjacency, &starts, |v| (adjacency[v].len(), v))
+                    .into_iter()
+                    .rev()
+                    .map(|v| ids[v])
+                    .collect()
+            }
+            NodeOrder::Gorder { window } => {
+                let adjacency = adjacency(g, &ids);
+
+                gorder(&adjacency, window)
+                    .into_iter()
+                    .map(|v| ids[v])
+                    .collect()
+            }
+        }
+    }
+}
+
+// The neighbors and in-neighbors of every node of `ids`, as indices into `ids`, sorted.
+fn adjacency<Id, NL, EL, Ty>(g: &TypedGraphMap<Id, NL, EL, Ty>, ids: &[Id]) -> Vec<Vec<usize>>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+{
+    let index: HashMap<Id, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+    ids.iter()
+        .map(|&id| {
+            let node = g.get_node(id).unwrap_nodemap();
+            let mut neighbors: Vec<_> = node
+                .neighbor_set()
+                .iter()
+                .chain(node.in_neighbor_set())
+                .map(|n| index[n])
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+
+            neighbors
+        })
+        .collect()
+}
+
+// A breadth-first search from every unvisited node of `starts` in turn, visiting the
+// neighbors of a node in ascending order of `key`.
+fn bfs<K: Ord, F: Fn(usize) -> K>(
+    adjacency: &[Vec<usize>],
+    starts: &[usize],
+    key: F,
+) -> Vec<usize> {
+    let mut visited = vec![false; adjacency.len()];
+    let mut order = Vec::with_capacity(adjacency.len());
+
+    for &start in starts {
+        if visited[start] {
+            continue;
+        }
+
+        visited[start] = true;
+        let mut head = order.len();
+        order.push(start);
+
+        // The nodes from `head` on are the queue.
+        while head < order.len() {
+            let v = order[head];
+            head += 1;
+
+            let mut next: Vec<_> = adjacency[v]
+                .iter()
+                .cloned()
+                .filter(|&u| !visited[u])
+                .collect();
+            next.sort_by_key(|&u| key(u));
+
+            for u in next {
+                visited[u] = true;
+                order.push(u);
+            }
+        }
+    }
+
+    order
+}
+
+fn gorder(adjacency: &[Vec<usize>], window: usize) -> Vec<usize> {
+    let num_nodes = adjacency.len();
+    // The common neighbors through a hub are too many to tell anything, so they are skipped.
+    let max_degree = (num_nodes as f64).sqrt() as usize + 1;
+
+    let mut by_degree: Vec<_> = (0..num_nodes).collect();
+    by_degree.sort_by_key(|&v| Reverse(adjacency[v].len()));
+
+    let mut scores = vec![0usize; num_nodes];
+    let mut placed = vec![false; num_nodes];
+    // May hold outdated scores, which are skipped when popped.
+    let mut heap: BinaryHeap<(usize, Reverse<usize>)> = BinaryHeap::new();
+    let mut next_start = 0;
+    let mut order = Vec::with_capacity(num_nodes);
+
+    while order.len() < num_nodes {
+        let v = loop {
+            match heap.pop() {
+                Some((score, Reverse(v))) => {
+                    if !placed[v] && scores[v] == score {
+                        break v;
+                    }
+                }
+                None => {
+                    while placed[by_degree[next_start]] {
+                        next_start += 1;
+                    }
+                    break by_degree[next_start];
+                }
+            }
+        };
+
+        placed[v] = true;
+        order.push(v);
+
+        for_each_related(adjacency, v, max_degree, |u| {
+            scores[u] += 1;
+            if !placed[u] {
+                heap.push((scores[u], Reverse(u)));
+            }
+        });
+
+        if order.len() > window {
+            let old = order[order.len() - 1 - window];
+
+            for_each_related(adjacency, old, max_degree, |u| {
+                scores[u] -= 1;
+                if !placed[u] && scores[u] > 0 {
+                    heap.push((scores[u], Reverse(u)));
+                }
+            });
+        }
+    }
+
+    order
+}
+
+// Call `f` on every neighbor of `v`, and on every other neighbor of those of degree at most
+// `max_degree`, once per path from `v`.
+fn for_each_related<F: FnMut(usize)>(
+    adjacency: &[Vec<usize>],
+    v: usize,
+    max_degree: usize,
+    mut f: F,
+) {
+    for &u in adjacency[v].iter() {
+        f(u);
+
+        if adjacency[u].len() <= max_degree {
+            for &w in adjacency[u].iter() {
+                if w != v {
+                    f(w);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! The error type of the crate, returned by the `try_*` counterparts of the functions that
+//! panic on invalid input.
+//!
+//! The readers and writers of `io` return an `io::Result` instead, as they mostly fail on
+//! files. A `GraphError` raised while reading, e.g., a duplicate edge, is wrapped in an
+//! `io::Error` of the kind `InvalidData`, and can be recovered with `get_ref`.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use io::serde::FormatError;
+
+pub type Result<T> = ::std::result::Result<T, GraphError>;
+
+#[derive(Debug)]
+pub enum GraphError {
+    /// Failed to read or write a file.
+    Io(io::Error),
+    /// A serialized graph that can not be imported.
+    Format(FormatError),
+    /// An argument out of its valid range, e.g., too many edges for a generator.
+    InvalidArgument(String),
+    /// A node id not in the graph.
+    NodeNotFound(usize),
+    /// A node inserted again, under `DuplicatePolicy::Error`.
+    DuplicateNode(usize),
+    /// An edge inserted again, under `DuplicatePolicy::Error`.
+    DuplicateEdge(usize, usize),
+    /// A graph whose data are inconsistent, e.g., a static graph with unsorted neighbors.
+    InvalidGraph(String),
+    /// A node or an edge of another kind than expected, e.g., of a static graph where one of
+    /// a graph map is expected.
+    UnexpectedType {
+        expected: &'static str,
+        found: &'static str,
+    },
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GraphError::Io(ref e) => write!(f, "IO error: {}", e),
+            GraphError::Format(ref e) => write!(f, "Format error: {}", e),
+            GraphError::InvalidArgument(ref message) => write!(f, "{}", message),
+            GraphError::NodeNotFound(id) => write!(f, "Node {} does not exist.", id),
+            GraphError::DuplicateNode(id) => write!(f, "Node {} already exists.", id),
+            GraphError::DuplicateEdge(start, target) => {
+                write!(f, "Edge ({},{}) already exists.", start, target)
+            }
+            GraphError::InvalidGraph(ref message) => write!(f, "Invalid graph: {}", message),
+            GraphError::UnexpectedType { expected, found } => {
+                write!(f, "Mismatched type: expect {}, found {}.", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for GraphError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            GraphError::Io(ref e) => Some(e),
+            GraphError::Format(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GraphError {
+    fn from(error: io::Error) -> Self {
+        GraphError::Io(error)
+    }
+}
+
+impl From<FormatError> for GraphError {
+    fn from(error: FormatError) -> Self {
+        GraphError::Format(error)
+    }
+}
+
+// For the readers, which report errors as `io::Error`.
+impl From<GraphError> for io::Error {
+    fn from(error: GraphError) -> Self {
+        match error {
+            GraphError::Io(e) => e,
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
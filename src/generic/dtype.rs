@@ -1,5 +1,6 @@
-use std::fmt::{Debug, Display};
+use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::Hash;
+use std::str::FromStr;
 
 /// The default data type for graph indices is `u32`.
 #[cfg(not(feature = "usize_id"))]
@@ -14,6 +15,22 @@ pub type DefaultTy = Directed;
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
 pub enum Void {}
 
+impl Display for Void {
+    fn fmt(&self, _: &mut Formatter) -> Result {
+        match *self {}
+    }
+}
+
+/// No string can be parsed into `Void`, so that it can be used for graphs without labels
+/// when reading files where labels are optional.
+impl FromStr for Void {
+    type Err = ();
+
+    fn from_str(_: &str) -> ::std::result::Result<Self, Self::Err> {
+        Err(())
+    }
+}
+
 pub trait GraphType: Debug + Eq + Clone {
     fn is_directed() -> bool;
 }
@@ -1,3 +1,4 @@
+use error::{GraphError, Result};
 use generic::IdType;
 use graph_impl::Edge;
 
@@ -53,6 +54,36 @@ impl<'a, Id: 'a + IdType> EdgeType<'a, Id> {
             EdgeType::None => panic!("called `EdgeType::unwrap_staticedge()` on a `None` value"),
         }
     }
+
+    /// Same as `unwrap_edgemap`, but return an error instead of panicking.
+    pub fn try_unwrap_edgemap(self) -> Result<&'a Edge<Id>> {
+        match self {
+            EdgeType::EdgeMap(edge) => Ok(edge),
+            other => Err(GraphError::UnexpectedType {
+                expected: "EdgeMap",
+                found: other.type_name(),
+            }),
+        }
+    }
+
+    /// Same as `unwrap_staticedge`, but return an error instead of panicking.
+    pub fn try_unwrap_staticedge(self) -> Result<Edge<Id>> {
+        match self {
+            EdgeType::StaticEdge(edge) => Ok(edge),
+            other => Err(GraphError::UnexpectedType {
+                expected: "StaticEdge",
+                found: other.type_name(),
+            }),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match *self {
+            EdgeType::EdgeMap(_) => "EdgeMap",
+            EdgeType::StaticEdge(_) => "StaticEdge",
+            EdgeType::None => "None",
+        }
+    }
 }
 
 impl<'a, Id: IdType> EdgeTrait<Id> for EdgeType<'a, Id> {
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use error::{GraphError, Result};
 use generic::IdType;
 use generic::Iter;
 use generic::MapTrait;
@@ -73,6 +74,31 @@ pub trait GraphTrait<Id: IdType> {
     /// Return the number of neighbors of a given node.
     fn num_of_neighbors(&self, id: Id) -> usize;
 
+    /// Return an iterator over the neighbors of a given node, together with the label ids
+    /// of the edges to them. By default, every edge is looked up with `get_edge`.
+    fn neighbors_with_labels<'a>(&'a self, id: Id) -> Iter<'a, (Id, Option<Id>)>
+    where
+        Id: 'a,
+    {
+        Iter::new(Box::new(
+            self.neighbors_iter(id)
+                .map(move |n| (n, self.get_edge(id, n).get_label_id())),
+        ))
+    }
+
+    /// Return an iterator over the neighbors of a given node along the edges of label id
+    /// `label`.
+    fn neighbors_with_edge_label<'a>(&'a self, id: Id, label: Id) -> Iter<'a, Id>
+    where
+        Id: 'a,
+    {
+        Iter::new(Box::new(
+            self.neighbors_with_labels(id)
+                .filter(move |&(_, l)| l == Some(label))
+                .map(|(n, _)| n),
+        ))
+    }
+
     // Lookup the node label id by its id.
     //    fn get_node_label_id(&self, node_id: Id) -> Option<Id>;
 
@@ -115,6 +141,35 @@ pub trait GraphTrait<Id: IdType> {
     }
 }
 
+/// How a bulk insertion treats a node or an edge that is already in the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Keep the existing one as is.
+    Ignore,
+    /// Replace the label of the existing one.
+    Overwrite,
+    /// Stop with an error, keeping what has been inserted before.
+    Error,
+}
+
+/// What a bulk insertion has done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InsertStats {
+    /// The number of new nodes or edges.
+    pub inserted: usize,
+    /// The number of duplicates whose labels are replaced.
+    pub overwritten: usize,
+    /// The number of duplicates left as is.
+    pub ignored: usize,
+}
+
+impl InsertStats {
+    /// The number of duplicates, either overwritten or ignored.
+    pub fn duplicates(&self) -> usize {
+        self.overwritten + self.ignored
+    }
+}
+
 pub trait MutGraphTrait<Id: IdType, NL, EL> {
     /// Associated node type
     type N;
@@ -147,6 +202,75 @@ pub trait MutGraphTrait<Id: IdType, NL, EL> {
 
     /// Return an iterator over all edges(mutable) in the graph.
     fn edges_mut<'a>(&'a mut self) -> Iter<'a, &mut Self::E>;
+
+    /// Add all `(id, label)` pairs of `nodes`, treating the ids already in the graph
+    /// according to `policy`, without logging every duplicate as `add_node` does.
+    ///
+    /// By default, a node is overwritten by adding it again, which replaces its label as in
+    /// `TypedGraphMap::add_node`.
+    fn extend_nodes<I>(&mut self, nodes: I, policy: DuplicatePolicy) -> Result<InsertStats>
+    where
+        Self: Sized + GraphTrait<Id>,
+        I: IntoIterator<Item = (Id, Option<NL>)>,
+    {
+        let mut stats = InsertStats::default();
+
+        for (id, label) in nodes {
+            if !self.has_node(id) {
+                self.add_node(id, label);
+                stats.inserted += 1;
+
+                continue;
+            }
+
+            match policy {
+                DuplicatePolicy::Ignore => stats.ignored += 1,
+                DuplicatePolicy::Overwrite => {
+                    self.add_node(id, label);
+                    stats.overwritten += 1;
+                }
+                DuplicatePolicy::Error => return Err(GraphError::DuplicateNode(id.id())),
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Add all `(start, target, label)` triples of `edges`, treating the edges already in
+    /// the graph according to `policy`. As in `add_edge`, missing ends are added with
+    /// `None` label.
+    ///
+    /// By default, an edge is overwritten by removing it and adding it again.
+    fn extend_edges<I>(&mut self, edges: I, policy: DuplicatePolicy) -> Result<InsertStats>
+    where
+        Self: Sized + GraphTrait<Id>,
+        I: IntoIterator<Item = (Id, Id, Option<EL>)>,
+    {
+        let mut stats = InsertStats::default();
+
+        for (start, target, label) in edges {
+            if !self.has_edge(start, target) {
+                self.add_edge(start, target, label);
+                stats.inserted += 1;
+
+                continue;
+            }
+
+            match policy {
+                DuplicatePolicy::Ignore => stats.ignored += 1,
+                DuplicatePolicy::Overwrite => {
+                    self.remove_edge(start, target);
+                    self.add_edge(start, target, label);
+                    stats.overwritten += 1;
+                }
+                DuplicatePolicy::Error => {
+                    return Err(GraphError::DuplicateEdge(start.id(), target.id()))
+                }
+            }
+        }
+
+        Ok(stats)
+    }
 }
 
 pub trait GraphLabelTrait<Id: IdType, NL: Hash + Eq, EL: Hash + Eq>: GraphTrait<Id> {
@@ -231,4 +355,59 @@ pub trait DiGraphTrait<Id: IdType>: GraphTrait<Id> {
 
     /// Return the number of in-neighbors of a given node.
     fn num_of_in_neighbors(&self, id: Id) -> usize;
+
+    /// Return an iterator over the in-neighbors of a given node, together with the label
+    /// ids of the edges from them. By default, every edge is looked up with `get_edge`.
+    fn in_neighbors_with_labels<'a>(&'a self, id: Id) -> Iter<'a, (Id, Option<Id>)>
+    where
+        Id: 'a,
+    {
+        Iter::new(Box::new(
+            self.in_neighbors_iter(id)
+                .map(move |n| (n, self.get_edge(n, id).get_label_id())),
+        ))
+    }
+
+    /// Return an iterator over the in-neighbors of a given node along the edges of label id
+    /// `label`.
+    fn in_neighbors_with_edge_label<'a>(&'a self, id: Id, label: Id) -> Iter<'a, Id>
+    where
+        Id: 'a,
+    {
+        Iter::new(Box::new(
+            self.in_neighbors_with_labels(id)
+                .filter(move |&(_, l)| l == Some(label))
+                .map(|(n, _)| n),
+        ))
+    }
+}
+
+/// Iterators over a graph of concrete types, for the hot loops of graph algorithms.
+/// Unlike the boxed `Iter`s of `GraphTrait`, which cost an allocation and a virtual call
+/// per item, these can be inlined, e.g., the neighbors of a `StaticGraph` are iterated
+/// as a plain slice.
+///
+/// The lifetime is that of the borrowed graph, so a generic algorithm is written
+/// against `G: for<'a> GraphIterTrait<'a, Id>`.
+pub trait GraphIterTrait<'a, Id: IdType>: GraphTrait<Id> {
+    type NodeIndices: Iterator<Item = Id>;
+    type EdgeIndices: Iterator<Item = (Id, Id)>;
+    type Neighbors: Iterator<Item = Id>;
+
+    /// Same as `node_indices`, statically dispatched.
+    fn iter_node_indices(&'a self) -> Self::NodeIndices;
+
+    /// Same as `edge_indices`, statically dispatched.
+    fn iter_edge_indices(&'a self) -> Self::EdgeIndices;
+
+    /// Same as `neighbors_iter`, statically dispatched.
+    fn iter_neighbors(&'a self, id: Id) -> Self::Neighbors;
+}
+
+/// The directed counterpart of `GraphIterTrait`.
+pub trait DiGraphIterTrait<'a, Id: IdType>: GraphIterTrait<'a, Id> + DiGraphTrait<Id> {
+    type InNeighbors: Iterator<Item = Id>;
+
+    /// Same as `in_neighbors_iter`, statically dispatched.
+    fn iter_in_neighbors(&'a self, id: Id) -> Self::InNeighbors;
 }
@@ -1,3 +1,5 @@
+use error::{GraphError, Result};
+
 use generic::IdType;
 use generic::Iter;
 
@@ -76,6 +78,36 @@ impl<'a, Id: 'a + IdType> NodeType<'a, Id> {
             NodeType::None => panic!("called `NodeType::unwrap_staticnode()` on a `None` value"),
         }
     }
+
+    /// Same as `unwrap_nodemap`, but return an error instead of panicking.
+    pub fn try_unwrap_nodemap(self) -> Result<&'a NodeMap<Id>> {
+        match self {
+            NodeType::NodeMap(node) => Ok(node),
+            other => Err(GraphError::UnexpectedType {
+                expected: "NodeMap",
+                found: other.type_name(),
+            }),
+        }
+    }
+
+    /// Same as `unwrap_staticnode`, but return an error instead of panicking.
+    pub fn try_unwrap_staticnode(self) -> Result<StaticNode<Id>> {
+        match self {
+            NodeType::StaticNode(node) => Ok(node),
+            other => Err(GraphError::UnexpectedType {
+                expected: "StaticNode",
+                found: other.type_name(),
+            }),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match *self {
+            NodeType::NodeMap(_) => "NodeMap",
+            NodeType::StaticNode(_) => "StaticNode",
+            NodeType::None => "None",
+        }
+    }
 }
 
 impl<'a, Id: IdType> NodeTrait<Id> for NodeType<'a, Id> {
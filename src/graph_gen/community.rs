@@ -0,0 +1,537 @@
+//! Generators of graphs with planted communities. Besides the graph, each generator returns
+//! the ground-truth community of every node. If `node_label` is not empty, the nodes of
+//! community `c` are labelled `node_label[c % node_label.len()]`, while edges are labelled
+//! from `edge_label` as usual.
+
+use std::hash::Hash;
+
+use rand::{thread_rng, Rng};
+
+use error::{GraphError, Result};
+
+use generic::GraphType;
+use generic::IdType;
+use generic::{GraphTrait, MutGraphTrait};
+
+use graph_gen::helper::complete_edge_pairs;
+use graph_gen::label::{assign_degree_correlated_labels, Labels};
+use graph_impl::TypedGraphMap;
+use map::SetMap;
+
+/// A graph together with the community of every node.
+pub type CommunityGraph<Id, NL, EL, Ty> = (TypedGraphMap<Id, NL, EL, Ty>, Vec<usize>);
+
+/// Stochastic block model: the nodes are split into consecutive blocks of `sizes`, and
+/// each pair of nodes in blocks `i` and `j` is joined with probability `probabilities[i][j]`.
+/// For undirected graphs, `probabilities` should be symmetric.
+pub fn stochastic_block_model_graph<Id, NL, EL, Ty, ELs>(
+    sizes: &[usize],
+    probabilities: &[Vec<f32>],
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> CommunityGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+{
+    stochastic_block_model_graph_with_rng(
+        &mut thread_rng(),
+        sizes,
+        probabilities,
+        node_label,
+        edge_label,
+    )
+}
+
+/// `stochastic_block_model_graph` drawing random numbers from `rng`.
+pub fn stochastic_block_model_graph_with_rng<Id, NL, EL, Ty, ELs, R>(
+    rng: &mut R,
+    sizes: &[usize],
+    probabilities: &[Vec<f32>],
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> CommunityGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_stochastic_block_model_graph_with_rng(rng, sizes, probabilities, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `stochastic_block_model_graph_with_rng`, but return an error if `probabilities`
+/// is not a square matrix of the number of blocks in `[0,1]`.
+pub fn try_stochastic_block_model_graph_with_rng<Id, NL, EL, Ty, ELs, R>(
+    rng: &mut R,
+    sizes: &[usize],
+    probabilities: &[Vec<f32>],
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> Result<CommunityGraph<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if probabilities.len() != sizes.len()
+        || probabilities
+            .iter()
+            .any(|row| row.len() != sizes.len() || row.iter().any(|p| !(0f32..=1f32).contains(p)))
+    {
+        return Err(GraphError::InvalidArgument(
+            "probabilities must be a square matrix of the number of blocks in [0,1].".to_owned(),
+        ));
+    }
+
+    let blocks: Vec<usize> = sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &size)| (0..size).map(move |_| i))
+        .collect();
+
+    let edge_label = edge_label.into();
+    let mut g = community_graph::<Id, NL, EL, Ty>(&blocks, node_label, &edge_label);
+
+    for (s, d) in complete_edge_pairs::<Ty>(blocks.len()) {
+        if rng.gen_range(0f32, 1f32) < probabilities[blocks[s]][blocks[d]] {
+            let label = edge_label.draw(rng);
+            g.add_edge(Id::new(s), Id::new(d), label);
+        }
+    }
+
+    assign_edge_labels_by_degree(rng, &mut g, &edge_label);
+
+    Ok((g, blocks))
+}
+
+/// Planted partition model: `l` blocks of `k` nodes, where nodes in the same block are
+/// joined with probability `p_in` and nodes in different blocks with probability `p_out`.
+pub fn planted_partition_graph<Id, NL, EL, Ty, ELs>(
+    l: usize,
+    k: usize,
+    p_in: f32,
+    p_out: f32,
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> CommunityGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+{
+    planted_partition_graph_with_rng(&mut thread_rng(), l, k, p_in, p_out, node_label, edge_label)
+}
+
+/// `planted_partition_graph` drawing random numbers from `rng`.
+pub fn planted_partition_graph_with_rng<Id, NL, EL, Ty, ELs, R>(
+    rng: &mut R,
+    l: usize,
+    k: usize,
+    p_in: f32,
+    p_out: f32,
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> CommunityGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_planted_partition_graph_with_rng(rng, l, k, p_in, p_out, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `planted_partition_graph_with_rng`, but return an error if `p_in` or `p_out` is
+/// not in `[0,1]`.
+pub fn try_planted_partition_graph_with_rng<Id, NL, EL, Ty, ELs, R>(
+    rng: &mut R,
+    l: usize,
+    k: usize,
+    p_in: f32,
+    p_out: f32,
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> Result<CommunityGraph<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let sizes = vec![k; l];
+    let probabilities: Vec<Vec<f32>> = (0..l)
+        .map(|i| (0..l).map(|j| if i == j { p_in } else { p_out }).collect())
+        .collect();
+
+    try_stochastic_block_model_graph_with_rng(rng, &sizes, &probabilities, node_label, edge_label)
+}
+
+/// Parameters of `lfr_benchmark_graph`.
+#[derive(Debug, Clone)]
+pub struct LfrParams {
+    /// The number of nodes.
+    pub n: usize,
+    /// The power-law exponent of the degree distribution, larger than 1.
+    pub tau1: f64,
+    /// The power-law exponent of the community size distribution, larger than 1.
+    pub tau2: f64,
+    /// The fraction of the edges of each node going out of its community, in [0,1].
+    pub mu: f64,
+    /// The average degree, in [1,max_degree].
+    pub average_degree: f64,
+    /// The maximum degree, in [1,n).
+    pub max_degree: usize,
+    pub min_community: usize,
+    pub max_community: usize,
+}
+
+/// LFR-style benchmark graph (Lancichinetti, Fortunato & Radicchi, 2008): node degrees and
+/// community sizes follow power laws, and each node has about a fraction `mu` of its edges
+/// going out of its community. Stubs are paired as in the configuration model, erasing
+/// self-loops and multi-edges, so the degrees are approximate. Only undirected graphs are
+/// supported.
+pub fn lfr_benchmark_graph<Id, NL, EL, Ty, ELs>(
+    params: &LfrParams,
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> CommunityGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+{
+    lfr_benchmark_graph_with_rng(&mut thread_rng(), params, node_label, edge_label)
+}
+
+/// `lfr_benchmark_graph` drawing random numbers from `rng`.
+pub fn lfr_benchmark_graph_with_rng<Id, NL, EL, Ty, ELs, R>(
+    rng: &mut R,
+    params: &LfrParams,
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> CommunityGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_lfr_benchmark_graph_with_rng(rng, params, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `lfr_benchmark_graph_with_rng`, but return an error if `params` are out of range
+/// or the graph is directed.
+pub fn try_lfr_benchmark_graph_with_rng<Id, NL, EL, Ty, ELs, R>(
+    rng: &mut R,
+    params: &LfrParams,
+    node_label: Vec<NL>,
+    edge_label: ELs,
+) -> Result<CommunityGraph<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if Ty::is_directed() {
+        return Err(GraphError::InvalidArgument(
+            "Only undirected graphs are supported.".to_owned(),
+        ));
+    }
+
+    let n = params.n;
+
+    if !(params.tau1 > 1f64 && params.tau2 > 1f64) {
+        return Err(GraphError::InvalidArgument(
+            "tau1 and tau2 must be larger than 1.".to_owned(),
+        ));
+    }
+
+    if !(0f64..=1f64).contains(&params.mu) {
+        return Err(GraphError::InvalidArgument(
+            "mu must be in the range of [0,1].".to_owned(),
+        ));
+    }
+
+    if params.max_degree < 1 || params.max_degree >= n {
+        return Err(GraphError::InvalidArgument(
+            "max_degree must be in the range of [1,n).".to_owned(),
+        ));
+    }
+
+    if !(params.average_degree >= 1f64 && params.average_degree <= params.max_degree as f64) {
+        return Err(GraphError::InvalidArgument(
+            "average_degree must be in the range of [1,max_degree].".to_owned(),
+        ));
+    }
+
+    if params.min_community < 1
+        || params.min_community > params.max_community
+        || params.max_community > n
+    {
+        return Err(GraphError::InvalidArgument(
+            "min_community and max_community must satisfy 1 <= min <= max <= n.".to_owned(),
+        ));
+    }
+
+    // Degrees.
+    let min_degree = (1..params.max_degree + 1)
+        .map(|d| {
+            let mean = power_law_mean(d, params.max_degree, params.tau1);
+            (d, (mean - params.average_degree).abs())
+        })
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map(|(d, _)| d)
+        .unwrap();
+
+    let degree_weights = power_law_weights(min_degree, params.max_degree, params.tau1);
+    let degrees: Vec<usize> = (0..n)
+        .map(|_| min_degree + sample(rng, &degree_weights))
+        .collect();
+
+    // Community sizes, which sum up to `n`.
+    let size_weights = power_law_weights(params.min_community, params.max_community, params.tau2);
+    let mut sizes = Vec::new();
+    let mut total = 0;
+
+    while total < n {
+        let size = params.min_community + sample(rng, &size_weights);
+
+        if total + size <= n {
+            sizes.push(size);
+            total += size;
+        } else if n - total >= params.min_community || sizes.is_empty() {
+            sizes.push(n - total);
+            total = n;
+        } else {
+            // Spread the remaining nodes over the communities that are not full.
+            while total < n {
+                let candidates: Vec<usize> = (0..sizes.len())
+                    .filter(|&c| sizes[c] < params.max_community)
+                    .collect();
+                let c = match rng.choose(&candidates) {
+                    Some(&c) => c,
+                    None => rng.gen_range(0, sizes.len()),
+                };
+
+                sizes[c] += 1;
+                total += 1;
+            }
+        }
+    }
+
+    // Assign nodes to communities, from the highest degree, so that the internal degree of
+    // each node fits in its community whenever possible.
+    let mut internal_degrees: Vec<usize> = degrees
+        .iter()
+        .map(|&d| ((1f64 - params.mu) * d as f64).round() as usize)
+        .collect();
+
+    let mut order: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut order);
+    order.sort_by(|&a, &b| degrees[b].cmp(&degrees[a]));
+
+    let mut free = sizes.clone();
+    let mut communities = vec![0; n];
+
+    for u in order {
+        let fit: Vec<usize> = (0..sizes.len())
+            .filter(|&c| free[c] > 0 && sizes[c] > internal_degrees[u])
+            .collect();
+
+        let c = match rng.choose(&fit) {
+            Some(&c) => c,
+            None => {
+                let open: Vec<usize> = (0..sizes.len()).filter(|&c| free[c] > 0).collect();
+                let c = *rng.choose(&open).unwrap();
+
+                internal_degrees[u] = sizes[c] - 1;
+
+                c
+            }
+        };
+
+        communities[u] = c;
+        free[c] -= 1;
+    }
+
+    let edge_label = edge_label.into();
+    let mut g = community_graph::<Id, NL, EL, Ty>(&communities, node_label, &edge_label);
+
+    // Internal edges.
+    let mut members = vec![Vec::new(); sizes.len()];
+    for u in 0..n {
+        members[communities[u]].push(u);
+    }
+
+    for nodes in &members {
+        let stubs: Vec<usize> = nodes
+            .iter()
+            .flat_map(|&u| (0..internal_degrees[u]).map(move |_| u))
+            .collect();
+
+        wire_stubs(rng, &mut g, stubs, &edge_label, |_, _| true);
+    }
+
+    // External edges.
+    let stubs: Vec<usize> = (0..n)
+        .flat_map(|u| (0..degrees[u] - internal_degrees[u]).map(move |_| u))
+        .collect();
+
+    wire_stubs(rng, &mut g, stubs, &edge_label, |s, t| {
+        communities[s] != communities[t]
+    });
+
+    assign_edge_labels_by_degree(rng, &mut g, &edge_label);
+
+    Ok((g, communities))
+}
+
+// An edgeless graph whose node `i` is labelled after `communities[i]`.
+fn community_graph<Id, NL, EL, Ty>(
+    communities: &[usize],
+    node_label: Vec<NL>,
+    edge_label: &Labels<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    let labels = node_label.clone();
+
+    let mut g = TypedGraphMap::with_label_map(
+        SetMap::from_vec(node_label),
+        SetMap::from_vec(edge_label.get_labels().to_vec()),
+    );
+
+    for (i, &c) in communities.iter().enumerate() {
+        let label = if labels.is_empty() {
+            None
+        } else {
+            Some(labels[c % labels.len()].clone())
+        };
+
+        g.add_node(Id::new(i), label);
+    }
+
+    g
+}
+
+// Assign the edge labels if `DegreeCorrelated`, as the node labels follow the communities.
+fn assign_edge_labels_by_degree<Id, NL, EL, Ty, R>(
+    rng: &mut R,
+    g: &mut TypedGraphMap<Id, NL, EL, Ty>,
+    edge_label: &Labels<EL>,
+) where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    assign_degree_correlated_labels(rng, g, &Labels::from(Vec::new()), edge_label);
+}
+
+// Pair up `stubs` at random into edges accepted by `is_valid`, re-pairing the stubs of
+// rejected pairs (self-loops, multi-edges or invalid ones) for a few rounds.
+fn wire_stubs<Id, NL, EL, Ty, R, F>(
+    rng: &mut R,
+    g: &mut TypedGraphMap<Id, NL, EL, Ty>,
+    mut stubs: Vec<usize>,
+    edge_label: &Labels<EL>,
+    is_valid: F,
+) where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+    F: Fn(usize, usize) -> bool,
+{
+    for _ in 0..10 {
+        if stubs.len() < 2 {
+            break;
+        }
+
+        rng.shuffle(&mut stubs);
+
+        let mut rejected = Vec::new();
+
+        for pair in stubs.chunks(2) {
+            if pair.len() < 2 {
+                continue;
+            }
+
+            let (s, t) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+
+            if s != t && is_valid(s, t) && !g.has_edge(Id::new(s), Id::new(t)) {
+                let label = edge_label.draw(rng);
+                g.add_edge(Id::new(s), Id::new(t), label);
+            } else {
+                rejected.push(s);
+                rejected.push(t);
+            }
+        }
+
+        stubs = rejected;
+    }
+}
+
+// The unnormalized probabilities of `min..=max` under a power law of `exponent`.
+fn power_law_weights(min: usize, max: usize, exponent: f64) -> Vec<f64> {
+    (min..max + 1).map(|x| (x as f64).powf(-exponent)).collect()
+}
+
+fn power_law_mean(min: usize, max: usize, exponent: f64) -> f64 {
+    let weights = power_law_weights(min, max, exponent);
+    let total: f64 = weights.iter().sum();
+
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, w)| (min + i) as f64 * w)
+        .sum::<f64>()
+        / total
+}
+
+// Sample an index with probability proportional to `weights`.
+fn sample<R: Rng>(rng: &mut R, weights: &[f64]) -> usize {
+    let total: f64 = weights.iter().sum();
+    let mut r = rng.gen_range(0f64, total);
+
+    for (i, &w) in weights.iter().enumerate() {
+        if r < w {
+            return i;
+        }
+
+        r -= w;
+    }
+
+    weights.len() - 1
+}
@@ -1,62 +1,665 @@
 use std::hash::Hash;
 
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
+
+use error::{GraphError, Result};
 
 use generic::GraphType;
 use generic::IdType;
-use generic::MutGraphTrait;
 
 use graph_impl::TypedGraphMap;
-use map::SetMap;
 
-use graph_gen::helper::{complete_edge_pairs, random_edge_label, random_node_label};
+use graph_gen::helper::{add_labeled_edges, complete_edge_pairs, labeled_empty_graph};
+use graph_gen::label::Labels;
 
-pub fn empty_graph<Id, NL, EL, Ty>(
+pub fn empty_graph<Id, NL, EL, Ty, NLs, ELs>(
     n: usize,
-    node_label: Vec<NL>,
-    edge_label: Vec<EL>,
+    node_label: NLs,
+    edge_label: ELs,
 ) -> TypedGraphMap<Id, NL, EL, Ty>
 where
     Id: IdType,
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
 {
-    let mut rng = thread_rng();
+    empty_graph_with_rng(&mut thread_rng(), n, node_label, edge_label)
+}
 
-    let node_label_map = SetMap::from_vec(node_label);
-    let edge_label_map = SetMap::from_vec(edge_label);
+/// `empty_graph` drawing random numbers from `rng`.
+pub fn empty_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    graph_from_edges(rng, n, Vec::new(), node_label.into(), edge_label.into())
+}
+
+pub fn complete_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    complete_graph_with_rng(&mut thread_rng(), n, node_label, edge_label)
+}
 
-    let mut g = TypedGraphMap::with_label_map(node_label_map, edge_label_map);
+/// `complete_graph` drawing random numbers from `rng`.
+pub fn complete_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    graph_from_edges(
+        rng,
+        n,
+        complete_edge_pairs::<Ty>(n),
+        node_label.into(),
+        edge_label.into(),
+    )
+}
 
-    for i in 0..n {
-        let label = random_node_label(&mut rng, &g);
-        g.add_node(Id::new(i), label);
+/// Path of `n` nodes, `0 - 1 - ... - (n - 1)`.
+pub fn path_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    path_graph_with_rng(&mut thread_rng(), n, node_label, edge_label)
+}
+
+/// `path_graph` drawing random numbers from `rng`.
+pub fn path_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let edges = (1..n).map(|i| (i - 1, i));
+
+    graph_from_edges(rng, n, edges, node_label.into(), edge_label.into())
+}
+
+/// Cycle of `n >= 3` nodes, `0 - 1 - ... - (n - 1) - 0`.
+pub fn cycle_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    cycle_graph_with_rng(&mut thread_rng(), n, node_label, edge_label)
+}
+
+/// `cycle_graph` drawing random numbers from `rng`.
+pub fn cycle_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_cycle_graph_with_rng(rng, n, node_label, edge_label).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `cycle_graph_with_rng`, but return an error if `n < 3`.
+pub fn try_cycle_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if n < 3 {
+        return Err(GraphError::InvalidArgument(
+            "n must be at least 3.".to_owned(),
+        ));
     }
 
-    g
+    let edges = (0..n).map(|i| (i, (i + 1) % n));
+
+    Ok(graph_from_edges(
+        rng,
+        n,
+        edges,
+        node_label.into(),
+        edge_label.into(),
+    ))
 }
 
-pub fn complete_graph<Id, NL, EL, Ty>(
+/// Star of a center `0` and `n` leaves `1..=n`.
+pub fn star_graph<Id, NL, EL, Ty, NLs, ELs>(
     n: usize,
-    node_label: Vec<NL>,
-    edge_label: Vec<EL>,
+    node_label: NLs,
+    edge_label: ELs,
 ) -> TypedGraphMap<Id, NL, EL, Ty>
 where
     Id: IdType,
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
 {
-    let mut rng = thread_rng();
+    star_graph_with_rng(&mut thread_rng(), n, node_label, edge_label)
+}
+
+/// `star_graph` drawing random numbers from `rng`.
+pub fn star_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let edges = (1..n + 1).map(|i| (0, i));
+
+    graph_from_edges(rng, n + 1, edges, node_label.into(), edge_label.into())
+}
+
+/// Wheel of `n >= 4` nodes: a hub `0` joined to every node of the cycle `1 - ... - (n - 1) - 1`.
+pub fn wheel_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    wheel_graph_with_rng(&mut thread_rng(), n, node_label, edge_label)
+}
+
+/// `wheel_graph` drawing random numbers from `rng`.
+pub fn wheel_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_wheel_graph_with_rng(rng, n, node_label, edge_label).unwrap_or_else(|e| panic!("{}", e))
+}
 
-    let mut g = empty_graph::<Id, NL, EL, Ty>(n, node_label, edge_label);
-    for (s, d) in complete_edge_pairs::<Ty>(n) {
-        let label = random_edge_label(&mut rng, &g);
-        g.add_edge(Id::new(s), Id::new(d), label);
+/// Same as `wheel_graph_with_rng`, but return an error if `n < 4`.
+pub fn try_wheel_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if n < 4 {
+        return Err(GraphError::InvalidArgument(
+            "n must be at least 4.".to_owned(),
+        ));
     }
 
-    g
+    let edges = (1..n)
+        .map(|i| (0, i))
+        .chain((1..n).map(|i| (i, i % (n - 1) + 1)));
+
+    Ok(graph_from_edges(
+        rng,
+        n,
+        edges,
+        node_label.into(),
+        edge_label.into(),
+    ))
+}
+
+/// Grid of `dims[0] x dims[1] x ...` nodes, e.g., 2D or 3D, where node ids are assigned
+/// in row-major order and each node is joined to its adjacent nodes along every dimension.
+pub fn grid_graph<Id, NL, EL, Ty, NLs, ELs>(
+    dims: &[usize],
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    grid_graph_with_rng(&mut thread_rng(), dims, node_label, edge_label)
+}
+
+/// `grid_graph` drawing random numbers from `rng`.
+pub fn grid_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    dims: &[usize],
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let edges = lattice_edges(dims, false);
+
+    graph_from_edges(
+        rng,
+        dims.iter().product(),
+        edges,
+        node_label.into(),
+        edge_label.into(),
+    )
+}
+
+/// Grid as in `grid_graph` whose borders are joined along every dimension of size at
+/// least 3.
+pub fn torus_graph<Id, NL, EL, Ty, NLs, ELs>(
+    dims: &[usize],
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    torus_graph_with_rng(&mut thread_rng(), dims, node_label, edge_label)
+}
+
+/// `torus_graph` drawing random numbers from `rng`.
+pub fn torus_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    dims: &[usize],
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let edges = lattice_edges(dims, true);
+
+    graph_from_edges(
+        rng,
+        dims.iter().product(),
+        edges,
+        node_label.into(),
+        edge_label.into(),
+    )
+}
+
+/// Complete bipartite graph of parts `0..n1` and `n1..n1 + n2`, with edges from the first
+/// part to the second one.
+pub fn complete_bipartite_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n1: usize,
+    n2: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    complete_bipartite_graph_with_rng(&mut thread_rng(), n1, n2, node_label, edge_label)
+}
+
+/// `complete_bipartite_graph` drawing random numbers from `rng`.
+pub fn complete_bipartite_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n1: usize,
+    n2: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let edges = (0..n1).flat_map(|s| (n1..n1 + n2).map(move |t| (s, t)));
+
+    graph_from_edges(rng, n1 + n2, edges, node_label.into(), edge_label.into())
+}
+
+/// Balanced `k`-ary tree of height `h`, rooted at `0`, where the children of node `i` are
+/// `k * i + 1..=k * i + k`.
+pub fn balanced_tree_graph<Id, NL, EL, Ty, NLs, ELs>(
+    k: usize,
+    h: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    balanced_tree_graph_with_rng(&mut thread_rng(), k, h, node_label, edge_label)
+}
+
+/// `balanced_tree_graph` drawing random numbers from `rng`.
+pub fn balanced_tree_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    k: usize,
+    h: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_balanced_tree_graph_with_rng(rng, k, h, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `balanced_tree_graph_with_rng`, but return an error if `k` is zero.
+pub fn try_balanced_tree_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    k: usize,
+    h: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if k < 1 {
+        return Err(GraphError::InvalidArgument(
+            "k must be positive.".to_owned(),
+        ));
+    }
+
+    let n = (0..h + 1).map(|i| k.pow(i as u32)).sum();
+
+    let edges = (1..n).map(|i| ((i - 1) / k, i));
+
+    Ok(graph_from_edges(
+        rng,
+        n,
+        edges,
+        node_label.into(),
+        edge_label.into(),
+    ))
+}
+
+/// Hypercube of `2^d` nodes, where two nodes are adjacent if their ids differ in one bit.
+pub fn hypercube_graph<Id, NL, EL, Ty, NLs, ELs>(
+    d: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    hypercube_graph_with_rng(&mut thread_rng(), d, node_label, edge_label)
+}
+
+/// `hypercube_graph` drawing random numbers from `rng`.
+pub fn hypercube_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    d: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let edges = (0..1 << d)
+        .flat_map(|s| (0..d).map(move |b| (s, s ^ (1 << b))))
+        .filter(|&(s, t)| s < t);
+
+    graph_from_edges(rng, 1 << d, edges, node_label.into(), edge_label.into())
+}
+
+/// Petersen graph: an outer cycle `0..5`, an inner pentagram `5..10` and the spokes
+/// `(i, i + 5)`.
+pub fn petersen_graph<Id, NL, EL, Ty, NLs, ELs>(
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    petersen_graph_with_rng(&mut thread_rng(), node_label, edge_label)
+}
+
+/// `petersen_graph` drawing random numbers from `rng`.
+pub fn petersen_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let edges = (0..5).flat_map(|i| vec![(i, (i + 1) % 5), (i, i + 5), (i + 5, (i + 2) % 5 + 5)]);
+
+    graph_from_edges(rng, 10, edges, node_label.into(), edge_label.into())
+}
+
+/// Lollipop graph: a complete graph of `m` nodes `0..m` and a path of `n` nodes `m..m + n`
+/// attached to node `m - 1`.
+pub fn lollipop_graph<Id, NL, EL, Ty, NLs, ELs>(
+    m: usize,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    lollipop_graph_with_rng(&mut thread_rng(), m, n, node_label, edge_label)
+}
+
+/// `lollipop_graph` drawing random numbers from `rng`.
+pub fn lollipop_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    m: usize,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_lollipop_graph_with_rng(rng, m, n, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `lollipop_graph_with_rng`, but return an error if `m` is zero.
+pub fn try_lollipop_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    m: usize,
+    n: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if m < 1 {
+        return Err(GraphError::InvalidArgument(
+            "m must be positive.".to_owned(),
+        ));
+    }
+
+    let edges = complete_edge_pairs::<Ty>(m).chain((m..m + n).map(|i| (i - 1, i)));
+
+    Ok(graph_from_edges(
+        rng,
+        m + n,
+        edges,
+        node_label.into(),
+        edge_label.into(),
+    ))
 }
 
 pub fn empty_graph_unlabeled<Id, NL, EL, Ty>(n: usize) -> TypedGraphMap<Id, NL, EL, Ty>
@@ -78,3 +681,49 @@ where
 {
     complete_graph(n, Vec::new(), Vec::new())
 }
+
+// A graph of `n` nodes and the given `edges`.
+fn graph_from_edges<Id, NL, EL, Ty, R, I>(
+    rng: &mut R,
+    n: usize,
+    edges: I,
+    node_label: Labels<NL>,
+    edge_label: Labels<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
+    add_labeled_edges(rng, &mut g, edges, &node_label, &edge_label);
+
+    g
+}
+
+// The edges between adjacent nodes of a grid of `dims`, wrapping around if `periodic`.
+fn lattice_edges(dims: &[usize], periodic: bool) -> Vec<(usize, usize)> {
+    let n: usize = dims.iter().product();
+    let mut edges = Vec::new();
+
+    for s in 0..n {
+        let mut stride = 1;
+
+        for &dim in dims.iter().rev() {
+            let coordinate = (s / stride) % dim;
+
+            if coordinate + 1 < dim {
+                edges.push((s, s + stride));
+            } else if periodic && dim >= 3 {
+                edges.push((s, s + stride - dim * stride));
+            }
+
+            stride *= dim;
+        }
+    }
+
+    edges
+}
@@ -1,15 +1,18 @@
 use std::hash::Hash;
 
 use itertools::Itertools;
-use rand::{Rng, ThreadRng};
+use rand::{ChaChaRng, Rng, SeedableRng};
 
 use generic::GraphLabelTrait;
 use generic::GraphType;
 use generic::IdType;
 use generic::Iter;
 use generic::MapTrait;
+use generic::MutGraphTrait;
 
+use graph_gen::label::{assign_degree_correlated_labels, Labels};
 use graph_impl::TypedGraphMap;
+use map::SetMap;
 
 pub fn complete_edge_pairs<'a, Ty>(n: usize) -> Iter<'a, (usize, usize)>
 where
@@ -26,8 +29,8 @@ where
     }
 }
 
-pub fn random_node_label<Id, NL, EL, Ty>(
-    rng: &mut ThreadRng,
+pub fn random_node_label<Id, NL, EL, Ty, R>(
+    rng: &mut R,
     g: &TypedGraphMap<Id, NL, EL, Ty>,
 ) -> Option<NL>
 where
@@ -35,6 +38,7 @@ where
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    R: Rng,
 {
     let labels = g.get_node_label_map();
 
@@ -47,8 +51,8 @@ where
     labels.get_item(random_index).cloned()
 }
 
-pub fn random_edge_label<Id, NL, EL, Ty>(
-    rng: &mut ThreadRng,
+pub fn random_edge_label<Id, NL, EL, Ty, R>(
+    rng: &mut R,
     g: &TypedGraphMap<Id, NL, EL, Ty>,
 ) -> Option<EL>
 where
@@ -56,6 +60,7 @@ where
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    R: Rng,
 {
     let labels = g.get_edge_label_map();
 
@@ -67,3 +72,77 @@ where
 
     labels.get_item(random_index).cloned()
 }
+
+/// An edgeless graph of the nodes `0..n`, labelled from `node_label`, whose label maps hold
+/// `node_label` and `edge_label`.
+pub fn labeled_empty_graph<Id, NL, EL, Ty, R>(
+    rng: &mut R,
+    n: usize,
+    node_label: &Labels<NL>,
+    edge_label: &Labels<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    let node_label_map = SetMap::from_vec(node_label.get_labels().to_vec());
+    let edge_label_map = SetMap::from_vec(edge_label.get_labels().to_vec());
+
+    let mut g = TypedGraphMap::with_label_map(node_label_map, edge_label_map);
+
+    for i in 0..n {
+        let label = node_label.draw(rng);
+        g.add_node(Id::new(i), label);
+    }
+
+    g
+}
+
+/// Add `edges` to a graph from `labeled_empty_graph`, labelled from `edge_label`, then
+/// assign the labels of `node_label` and `edge_label` that are `DegreeCorrelated`.
+pub fn add_labeled_edges<Id, NL, EL, Ty, R, I>(
+    rng: &mut R,
+    g: &mut TypedGraphMap<Id, NL, EL, Ty>,
+    edges: I,
+    node_label: &Labels<NL>,
+    edge_label: &Labels<EL>,
+) where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    for (s, d) in edges {
+        let label = edge_label.draw(rng);
+        g.add_edge(Id::new(s), Id::new(d), label);
+    }
+
+    assign_degree_correlated_labels(rng, g, node_label, edge_label);
+}
+
+/// Sample `m` distinct values from `seq`, in which a value appearing more times is more
+/// likely to be sampled. `seq` must contain at least `m` distinct values.
+pub fn random_subset<R: Rng>(rng: &mut R, seq: &[usize], m: usize) -> Vec<usize> {
+    let mut targets = Vec::with_capacity(m);
+
+    while targets.len() < m {
+        let x = *rng.choose(seq).unwrap();
+
+        if !targets.contains(&x) {
+            targets.push(x);
+        }
+    }
+
+    targets
+}
+
+/// A random number generator seeded with `seed`, for the `*_with_rng` generators to produce
+/// the same graph on every run and platform.
+pub fn seeded_rng(seed: u64) -> ChaChaRng {
+    ChaChaRng::from_seed(&[seed as u32, (seed >> 32) as u32])
+}
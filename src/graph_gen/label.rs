@@ -0,0 +1,290 @@
+//! Non-uniform labels. Every generator taking labels accepts either a `Vec` of labels, drawn
+//! uniformly, or `Labels` drawn following a `LabelDistribution`, e.g.,
+//!
+//! ```
+//! use rust_graph::graph_gen::{random_gnm_graph, LabelDistribution, Labels};
+//! use rust_graph::graph_impl::UnGraphMap;
+//!
+//! let node_labels = Labels::new(vec!["a", "b", "c"], LabelDistribution::Zipf(1.5));
+//! let edge_labels = Labels::new(vec![1, 2], LabelDistribution::DegreeCorrelated);
+//! let g: UnGraphMap<&str, u8> = random_gnm_graph(100, 300, node_labels, edge_labels);
+//! ```
+//!
+//! An existing graph can be relabelled following another distribution with `assign_labels`.
+
+use std::hash::Hash;
+
+use rand::{thread_rng, Rng};
+
+use error::{GraphError, Result};
+use generic::IdType;
+use generic::MutGraphLabelTrait;
+
+/// How labels are drawn, where the `i`-th label is the `i`-th one given to the generator,
+/// or the `i`-th one in the label map of the graph for `assign_labels`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LabelDistribution {
+    /// Every label is equally likely, as with `random_node_label`.
+    Uniform,
+    /// The `i`-th label has a weight of `1 / (i + 1)^s`.
+    Zipf(f64),
+    /// The `i`-th label has the `i`-th weight, with as many weights as labels.
+    Weighted(Vec<f64>),
+    /// Labels are assigned by degree rank, in equal shares: the nodes (or edges, by the sum
+    /// of the degrees of their ends) of the highest degrees take the first label, and so on.
+    /// The generators assign these labels once all edges are generated.
+    DegreeCorrelated,
+}
+
+/// Labels to draw following a distribution, which the generators take in place of a `Vec`
+/// of labels. A `Vec` converts into uniformly drawn `Labels`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Labels<L> {
+    labels: Vec<L>,
+    distribution: LabelDistribution,
+    // The cumulative weights of the labels, for `Zipf` and `Weighted` only.
+    cumulative: Vec<f64>,
+}
+
+impl<L> Labels<L> {
+    pub fn new(labels: Vec<L>, distribution: LabelDistribution) -> Self {
+        Self::try_new(labels, distribution).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `new`, but return an error if the distribution has a negative or non-finite
+    /// weight or exponent, all zero weights, or not as many weights as labels.
+    pub fn try_new(labels: Vec<L>, distribution: LabelDistribution) -> Result<Self> {
+        let weights: Vec<f64> = match distribution {
+            LabelDistribution::Zipf(s) => {
+                if !s.is_finite() {
+                    return Err(GraphError::InvalidArgument(
+                        "The exponent of Zipf must be finite.".to_owned(),
+                    ));
+                }
+
+                (0..labels.len())
+                    .map(|i| ((i + 1) as f64).powf(-s))
+                    .collect()
+            }
+            LabelDistribution::Weighted(ref weights) => {
+                if weights.len() != labels.len()
+                    || weights.iter().any(|&w| !w.is_finite() || w < 0f64)
+                {
+                    return Err(GraphError::InvalidArgument(
+                        "There must be a finite non-negative weight for each of the labels."
+                            .to_owned(),
+                    ));
+                }
+
+                weights.clone()
+            }
+            LabelDistribution::Uniform | LabelDistribution::DegreeCorrelated => Vec::new(),
+        };
+
+        let cumulative: Vec<f64> = weights
+            .iter()
+            .scan(0f64, |sum, &w| {
+                *sum += w;
+                Some(*sum)
+            })
+            .collect();
+
+        if cumulative
+            .last()
+            .map_or(false, |&total| !total.is_finite() || total <= 0f64)
+        {
+            return Err(GraphError::InvalidArgument(
+                "The weights of the labels must not all be zero, nor sum up to infinity."
+                    .to_owned(),
+            ));
+        }
+
+        Ok(Labels {
+            labels,
+            distribution,
+            cumulative,
+        })
+    }
+
+    pub fn get_labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    pub fn get_distribution(&self) -> &LabelDistribution {
+        &self.distribution
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn is_degree_correlated(&self) -> bool {
+        self.distribution == LabelDistribution::DegreeCorrelated
+    }
+}
+
+impl<L: Clone> Labels<L> {
+    /// Draw a label, or `None` if there is no label, or if the labels are
+    /// `DegreeCorrelated`, as a single label carries no degree.
+    pub fn draw<R: Rng>(&self, rng: &mut R) -> Option<L> {
+        if self.labels.is_empty() {
+            return None;
+        }
+
+        let index = match self.distribution {
+            LabelDistribution::Uniform => rng.gen_range(0, self.labels.len()),
+            LabelDistribution::Zipf(_) | LabelDistribution::Weighted(_) => {
+                let total = self.cumulative[self.cumulative.len() - 1];
+                let r = rng.gen_range(0f64, total);
+
+                self.cumulative
+                    .partition_point(|&c| c <= r)
+                    .min(self.labels.len() - 1)
+            }
+            LabelDistribution::DegreeCorrelated => return None,
+        };
+
+        Some(self.labels[index].clone())
+    }
+
+    /// Draw a label for each item of the given `degrees`, which are `None` if there is no
+    /// label.
+    pub fn draw_by_degree<R: Rng>(&self, rng: &mut R, degrees: &[usize]) -> Vec<Option<L>> {
+        if self.labels.is_empty() {
+            return vec![None; degrees.len()];
+        }
+
+        if !self.is_degree_correlated() {
+            return degrees.iter().map(|_| self.draw(rng)).collect();
+        }
+
+        let mut order: Vec<usize> = (0..degrees.len()).collect();
+        rng.shuffle(&mut order);
+        order.sort_by(|&a, &b| degrees[b].cmp(&degrees[a]));
+
+        let mut labels = vec![None; degrees.len()];
+        for (rank, i) in order.into_iter().enumerate() {
+            labels[i] = Some(self.labels[rank * self.labels.len() / degrees.len()].clone());
+        }
+
+        labels
+    }
+}
+
+impl<L> From<Vec<L>> for Labels<L> {
+    fn from(labels: Vec<L>) -> Self {
+        Labels::new(labels, LabelDistribution::Uniform)
+    }
+}
+
+/// Redraw the labels of all nodes and edges of `g` from its label maps, following
+/// `node_distribution` and `edge_distribution`. Nodes or edges are left unlabelled if
+/// the corresponding label map is empty.
+pub fn assign_labels<Id, NL, EL, G>(
+    g: &mut G,
+    node_distribution: &LabelDistribution,
+    edge_distribution: &LabelDistribution,
+) where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    G: MutGraphLabelTrait<Id, NL, EL>,
+{
+    assign_labels_with_rng(&mut thread_rng(), g, node_distribution, edge_distribution)
+}
+
+/// `assign_labels` drawing random numbers from `rng`.
+pub fn assign_labels_with_rng<Id, NL, EL, G, R>(
+    rng: &mut R,
+    g: &mut G,
+    node_distribution: &LabelDistribution,
+    edge_distribution: &LabelDistribution,
+) where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    G: MutGraphLabelTrait<Id, NL, EL>,
+    R: Rng,
+{
+    let node_labels = Labels::new(
+        g.node_labels().cloned().collect(),
+        node_distribution.clone(),
+    );
+    assign_node_labels(rng, g, &node_labels);
+
+    let edge_labels = Labels::new(
+        g.edge_labels().cloned().collect(),
+        edge_distribution.clone(),
+    );
+    assign_edge_labels(rng, g, &edge_labels);
+}
+
+/// Assign the labels of the nodes or edges of a generated graph `g` whose `node_label` or
+/// `edge_label` are `DegreeCorrelated`, which are left unlabelled while generating it.
+pub fn assign_degree_correlated_labels<Id, NL, EL, G, R>(
+    rng: &mut R,
+    g: &mut G,
+    node_label: &Labels<NL>,
+    edge_label: &Labels<EL>,
+) where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    G: MutGraphLabelTrait<Id, NL, EL>,
+    R: Rng,
+{
+    if node_label.is_degree_correlated() {
+        assign_node_labels(rng, g, node_label);
+    }
+
+    if edge_label.is_degree_correlated() {
+        assign_edge_labels(rng, g, edge_label);
+    }
+}
+
+fn assign_node_labels<Id, NL, EL, G, R>(rng: &mut R, g: &mut G, labels: &Labels<NL>)
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    G: MutGraphLabelTrait<Id, NL, EL>,
+    R: Rng,
+{
+    if labels.is_empty() {
+        return;
+    }
+
+    let mut nodes: Vec<Id> = g.node_indices().collect();
+    nodes.sort();
+
+    let degrees: Vec<usize> = nodes.iter().map(|&id| g.degree(id)).collect();
+
+    for (&id, label) in nodes.iter().zip(labels.draw_by_degree(rng, &degrees)) {
+        g.update_node_label(id, label);
+    }
+}
+
+fn assign_edge_labels<Id, NL, EL, G, R>(rng: &mut R, g: &mut G, labels: &Labels<EL>)
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    G: MutGraphLabelTrait<Id, NL, EL>,
+    R: Rng,
+{
+    if labels.is_empty() {
+        return;
+    }
+
+    let mut edges: Vec<(Id, Id)> = g.edge_indices().collect();
+    edges.sort();
+
+    let degrees: Vec<usize> = edges
+        .iter()
+        .map(|&(s, t)| g.degree(s) + g.degree(t))
+        .collect();
+
+    for (&(s, t), label) in edges.iter().zip(labels.draw_by_degree(rng, &degrees)) {
+        g.update_edge_label(s, t, label);
+    }
+}
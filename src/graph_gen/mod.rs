@@ -1,8 +1,80 @@
+pub mod community;
 pub mod general;
 pub mod helper;
+pub mod label;
+pub mod preferential;
+pub mod query;
 pub mod random;
+pub mod rmat;
+pub mod temporal;
 
-pub use graph_gen::general::{complete_graph, complete_graph_unlabeled};
-pub use graph_gen::general::{empty_graph, empty_graph_unlabeled};
-pub use graph_gen::random::{random_gnm_graph, random_gnm_graph_unlabeled};
-pub use graph_gen::random::{random_gnp_graph, random_gnp_graph_unlabeled};
+pub use graph_gen::community::LfrParams;
+pub use graph_gen::community::{
+    lfr_benchmark_graph, lfr_benchmark_graph_with_rng, try_lfr_benchmark_graph_with_rng,
+};
+pub use graph_gen::community::{
+    planted_partition_graph, planted_partition_graph_with_rng, try_planted_partition_graph_with_rng,
+};
+pub use graph_gen::community::{
+    stochastic_block_model_graph, stochastic_block_model_graph_with_rng,
+    try_stochastic_block_model_graph_with_rng,
+};
+pub use graph_gen::general::{
+    balanced_tree_graph, balanced_tree_graph_with_rng, try_balanced_tree_graph_with_rng,
+};
+pub use graph_gen::general::{complete_bipartite_graph, complete_bipartite_graph_with_rng};
+pub use graph_gen::general::{complete_graph, complete_graph_unlabeled, complete_graph_with_rng};
+pub use graph_gen::general::{cycle_graph, cycle_graph_with_rng, try_cycle_graph_with_rng};
+pub use graph_gen::general::{empty_graph, empty_graph_unlabeled, empty_graph_with_rng};
+pub use graph_gen::general::{grid_graph, grid_graph_with_rng};
+pub use graph_gen::general::{hypercube_graph, hypercube_graph_with_rng};
+pub use graph_gen::general::{
+    lollipop_graph, lollipop_graph_with_rng, try_lollipop_graph_with_rng,
+};
+pub use graph_gen::general::{path_graph, path_graph_with_rng};
+pub use graph_gen::general::{petersen_graph, petersen_graph_with_rng};
+pub use graph_gen::general::{star_graph, star_graph_with_rng};
+pub use graph_gen::general::{torus_graph, torus_graph_with_rng};
+pub use graph_gen::general::{try_wheel_graph_with_rng, wheel_graph, wheel_graph_with_rng};
+pub use graph_gen::helper::seeded_rng;
+pub use graph_gen::label::{assign_degree_correlated_labels, LabelDistribution, Labels};
+pub use graph_gen::label::{assign_labels, assign_labels_with_rng};
+pub use graph_gen::preferential::{
+    barabasi_albert_graph, barabasi_albert_graph_with_rng, try_barabasi_albert_graph_with_rng,
+};
+pub use graph_gen::preferential::{
+    extended_barabasi_albert_graph, extended_barabasi_albert_graph_with_rng,
+    try_extended_barabasi_albert_graph_with_rng,
+};
+pub use graph_gen::preferential::{
+    holme_kim_graph, holme_kim_graph_with_rng, try_holme_kim_graph_with_rng,
+};
+pub use graph_gen::query::{Query, QueryDensity, QueryGenerator, QueryStrategy};
+pub use graph_gen::random::{
+    configuration_model_graph, configuration_model_graph_with_rng,
+    try_configuration_model_graph_with_rng,
+};
+pub use graph_gen::random::{
+    newman_watts_graph, newman_watts_graph_with_rng, try_newman_watts_graph_with_rng,
+};
+pub use graph_gen::random::{
+    random_gnm_graph, random_gnm_graph_unlabeled, random_gnm_graph_with_rng,
+    try_random_gnm_graph_with_rng,
+};
+pub use graph_gen::random::{
+    random_gnp_graph, random_gnp_graph_unlabeled, random_gnp_graph_with_rng,
+    try_random_gnp_graph_with_rng,
+};
+pub use graph_gen::random::{
+    random_regular_graph, random_regular_graph_with_rng, try_random_regular_graph_with_rng,
+};
+pub use graph_gen::random::{
+    try_watts_strogatz_graph_with_rng, watts_strogatz_graph, watts_strogatz_graph_with_rng,
+};
+pub use graph_gen::rmat::{graph500_graph, rmat_graph, RMatGenerator};
+pub use graph_gen::temporal::{
+    forest_fire_stream, forest_fire_stream_with_rng, try_forest_fire_stream_with_rng,
+};
+pub use graph_gen::temporal::{
+    shuffled_edge_stream, shuffled_edge_stream_with_rng, try_shuffled_edge_stream_with_rng,
+};
@@ -0,0 +1,414 @@
+use std::hash::Hash;
+
+use rand::{thread_rng, Rng};
+
+use error::{GraphError, Result};
+
+use generic::GraphType;
+use generic::IdType;
+use generic::{GraphTrait, MutGraphTrait};
+
+use graph_gen::helper::{labeled_empty_graph, random_subset};
+use graph_gen::label::{assign_degree_correlated_labels, Labels};
+use graph_impl::TypedGraphMap;
+
+/// Barabási–Albert graph of `n` nodes: starting from `m` isolated nodes, every new node
+/// is attached to `m` distinct existing nodes chosen with probability proportional to
+/// their degrees.
+pub fn barabasi_albert_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    m: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    barabasi_albert_graph_with_rng(&mut thread_rng(), n, m, node_label, edge_label)
+}
+
+/// `barabasi_albert_graph` drawing random numbers from `rng`.
+pub fn barabasi_albert_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_barabasi_albert_graph_with_rng(rng, n, m, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `barabasi_albert_graph_with_rng`, but return an error if `m` is not in `[1,n)`.
+pub fn try_barabasi_albert_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if m < 1 || m >= n {
+        return Err(GraphError::InvalidArgument(
+            "m must be in the range of [1,n).".to_owned(),
+        ));
+    }
+
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
+
+    // Every node appears here once per incident edge.
+    let mut repeated_nodes = Vec::with_capacity(2 * (n - m) * m);
+    let mut targets: Vec<usize> = (0..m).collect();
+
+    for source in m..n {
+        for &target in &targets {
+            let label = edge_label.draw(rng);
+            g.add_edge(Id::new(source), Id::new(target), label);
+        }
+
+        repeated_nodes.extend_from_slice(&targets);
+        repeated_nodes.extend((0..m).map(|_| source));
+
+        targets = random_subset(rng, &repeated_nodes, m);
+    }
+
+    assign_degree_correlated_labels(rng, &mut g, &node_label, &edge_label);
+
+    Ok(g)
+}
+
+/// Extended Barabási–Albert graph (Albert & Barabási, 2000) of `n` nodes. At each step,
+/// with probability `p`, `m` new edges are added between existing nodes; with probability
+/// `q`, `m` existing edges are rewired; otherwise a new node is attached to `m` existing
+/// nodes. All endpoints are chosen preferentially. Only undirected graphs are supported.
+pub fn extended_barabasi_albert_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    m: usize,
+    p: f32,
+    q: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    extended_barabasi_albert_graph_with_rng(&mut thread_rng(), n, m, p, q, node_label, edge_label)
+}
+
+/// `extended_barabasi_albert_graph` drawing random numbers from `rng`.
+pub fn extended_barabasi_albert_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    p: f32,
+    q: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_extended_barabasi_albert_graph_with_rng(rng, n, m, p, q, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `extended_barabasi_albert_graph_with_rng`, but return an error if `m` is not in
+/// `[1,n)`, `p` or `q` is negative, `p + q >= 1` or the graph is directed.
+pub fn try_extended_barabasi_albert_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    p: f32,
+    q: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if Ty::is_directed() {
+        return Err(GraphError::InvalidArgument(
+            "Only undirected graphs are supported.".to_owned(),
+        ));
+    }
+
+    if m < 1 || m >= n {
+        return Err(GraphError::InvalidArgument(
+            "m must be in the range of [1,n).".to_owned(),
+        ));
+    }
+
+    if p < 0f32 || q < 0f32 || p + q >= 1f32 {
+        return Err(GraphError::InvalidArgument(
+            "p and q must be non-negative with p + q < 1.".to_owned(),
+        ));
+    }
+
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
+
+    // Every node appears here once, plus once per incident edge.
+    let mut attachment_preference: Vec<usize> = (0..m).collect();
+    let mut new_node = m;
+
+    while new_node < n {
+        let a_probability = rng.gen_range(0f32, 1f32);
+
+        let clique_degree = new_node - 1;
+        let clique_size = new_node * clique_degree / 2;
+        let size = g.edge_count();
+
+        if a_probability < p && size + m <= clique_size {
+            // Add `m` new edges.
+            let mut eligible_nodes: Vec<usize> = (0..new_node)
+                .filter(|&i| g.degree(Id::new(i)) < clique_degree)
+                .collect();
+
+            for _ in 0..m {
+                let src = match rng.choose(&eligible_nodes) {
+                    Some(&src) => src,
+                    None => break,
+                };
+
+                let candidates: Vec<usize> = attachment_preference
+                    .iter()
+                    .cloned()
+                    .filter(|&i| i != src && !g.has_edge(Id::new(src), Id::new(i)))
+                    .collect();
+                let dest = *rng.choose(&candidates).unwrap();
+
+                let label = edge_label.draw(rng);
+                g.add_edge(Id::new(src), Id::new(dest), label);
+
+                attachment_preference.push(src);
+                attachment_preference.push(dest);
+
+                eligible_nodes.retain(|&i| g.degree(Id::new(i)) < clique_degree);
+            }
+        } else if a_probability < p + q && m <= size && size < clique_size {
+            // Rewire `m` edges.
+            let mut eligible_nodes: Vec<usize> = (0..new_node)
+                .filter(|&i| {
+                    let degree = g.degree(Id::new(i));
+                    degree > 0 && degree < clique_degree
+                })
+                .collect();
+
+            for _ in 0..m {
+                let node = match rng.choose(&eligible_nodes) {
+                    Some(&node) => node,
+                    None => break,
+                };
+
+                let neighbors: Vec<usize> =
+                    g.neighbors_iter(Id::new(node)).map(|i| i.id()).collect();
+                let src = *rng.choose(&neighbors).unwrap();
+
+                let candidates: Vec<usize> = attachment_preference
+                    .iter()
+                    .cloned()
+                    .filter(|&i| i != node && !neighbors.contains(&i))
+                    .collect();
+                let dest = *rng.choose(&candidates).unwrap();
+
+                g.remove_edge(Id::new(node), Id::new(src));
+
+                let label = edge_label.draw(rng);
+                g.add_edge(Id::new(node), Id::new(dest), label);
+
+                let index = attachment_preference
+                    .iter()
+                    .position(|&i| i == src)
+                    .unwrap();
+                attachment_preference.swap_remove(index);
+                attachment_preference.push(dest);
+
+                eligible_nodes = (0..new_node)
+                    .filter(|&i| {
+                        let degree = g.degree(Id::new(i));
+                        degree > 0 && degree < clique_degree
+                    })
+                    .collect();
+            }
+        } else {
+            // Add a new node.
+            let targets = random_subset(rng, &attachment_preference, m);
+
+            for &target in &targets {
+                let label = edge_label.draw(rng);
+                g.add_edge(Id::new(new_node), Id::new(target), label);
+            }
+
+            attachment_preference.extend_from_slice(&targets);
+            attachment_preference.extend((0..m + 1).map(|_| new_node));
+
+            new_node += 1;
+        }
+    }
+
+    assign_degree_correlated_labels(rng, &mut g, &node_label, &edge_label);
+
+    Ok(g)
+}
+
+/// Holme–Kim graph of `n` nodes, i.e., a Barabási–Albert graph with triad formation: after
+/// a new node is attached to a preferentially chosen node, each of its remaining `m - 1`
+/// edges closes a triangle with a neighbor of that node with probability `p`.
+pub fn holme_kim_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    m: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    holme_kim_graph_with_rng(&mut thread_rng(), n, m, p, node_label, edge_label)
+}
+
+/// `holme_kim_graph` drawing random numbers from `rng`.
+pub fn holme_kim_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_holme_kim_graph_with_rng(rng, n, m, p, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `holme_kim_graph_with_rng`, but return an error if `m` is not in `[1,n)` or `p`
+/// is not in `[0,1]`.
+pub fn try_holme_kim_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if m < 1 || m >= n {
+        return Err(GraphError::InvalidArgument(
+            "m must be in the range of [1,n).".to_owned(),
+        ));
+    }
+
+    if !(0f32..=1f32).contains(&p) {
+        return Err(GraphError::InvalidArgument(
+            "p must be in the range of [0,1].".to_owned(),
+        ));
+    }
+
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
+
+    let mut repeated_nodes: Vec<usize> = (0..m).collect();
+
+    for source in m..n {
+        let mut possible_targets = random_subset(rng, &repeated_nodes, m);
+
+        let mut target = possible_targets.pop().unwrap();
+        let label = edge_label.draw(rng);
+        g.add_edge(Id::new(source), Id::new(target), label);
+        repeated_nodes.push(target);
+
+        let mut count = 1;
+
+        while count < m {
+            if rng.gen_range(0f32, 1f32) < p {
+                let neighborhood: Vec<usize> = g
+                    .neighbors_iter(Id::new(target))
+                    .map(|i| i.id())
+                    .filter(|&i| i != source && !g.has_edge(Id::new(source), Id::new(i)))
+                    .collect();
+
+                if let Some(&neighbor) = rng.choose(&neighborhood) {
+                    let label = edge_label.draw(rng);
+                    g.add_edge(Id::new(source), Id::new(neighbor), label);
+                    repeated_nodes.push(neighbor);
+                    count += 1;
+
+                    continue;
+                }
+            }
+
+            target = possible_targets.pop().unwrap();
+            let label = edge_label.draw(rng);
+            g.add_edge(Id::new(source), Id::new(target), label);
+            repeated_nodes.push(target);
+            count += 1;
+        }
+
+        repeated_nodes.extend((0..m).map(|_| source));
+    }
+
+    assign_degree_correlated_labels(rng, &mut g, &node_label, &edge_label);
+
+    Ok(g)
+}
@@ -0,0 +1,359 @@
+//! Query graphs extracted from a data graph, for benchmarking subgraph matching. Every
+//! extracted query is connected and comes with the embedding it was extracted from, so it
+//! is guaranteed to have at least one match in the data graph.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use rand::{thread_rng, Rng};
+
+use error::{GraphError, Result};
+use generic::GeneralGraph;
+use generic::GraphType;
+use generic::IdType;
+use generic::MutGraphTrait;
+
+use graph_impl::TypedGraphMap;
+
+/// A query together with its embedding in the data graph.
+pub type Query<Id, NL, EL, Ty> = (TypedGraphMap<Id, NL, EL, Ty>, Vec<Id>);
+
+// The picked nodes in order of visit, with the (data) edges that first reached them.
+type Picked<Id> = (Vec<Id>, Vec<(Id, Id)>);
+
+/// How the nodes of a query are picked from the data graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryStrategy {
+    /// A random walk from a random node, until enough distinct nodes are visited.
+    RandomWalk,
+    /// A breadth-first search from a random node, visiting neighbors in random order.
+    Bfs,
+}
+
+/// How many of the edges among the picked nodes are kept in a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryDensity {
+    /// The edges that reached the picked nodes, plus random others while the average
+    /// degree stays below 3.
+    Sparse,
+    /// All the edges among the picked nodes, which must have an average degree of at
+    /// least 3.
+    Dense,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryGenerator {
+    num_nodes: usize,
+    strategy: QueryStrategy,
+    density: QueryDensity,
+    preserve_labels: bool,
+    max_attempts: usize,
+}
+
+impl QueryGenerator {
+    /// By default, queries are extracted by random walks, are sparse and keep the labels
+    /// of the data graph.
+    pub fn new(num_nodes: usize) -> Self {
+        Self::try_new(num_nodes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `new`, but return an error if `num_nodes` is zero.
+    pub fn try_new(num_nodes: usize) -> Result<Self> {
+        if num_nodes < 1 {
+            return Err(GraphError::InvalidArgument(
+                "num_nodes must be positive.".to_owned(),
+            ));
+        }
+
+        Ok(QueryGenerator {
+            num_nodes,
+            strategy: QueryStrategy::RandomWalk,
+            density: QueryDensity::Sparse,
+            preserve_labels: true,
+            max_attempts: 100,
+        })
+    }
+
+    pub fn with_strategy(mut self, strategy: QueryStrategy) -> Self {
+        self.strategy = strategy;
+
+        self
+    }
+
+    pub fn with_density(mut self, density: QueryDensity) -> Self {
+        self.density = density;
+
+        self
+    }
+
+    /// Whether the query keeps the node and edge labels of the data graph, or is unlabelled.
+    pub fn with_labels(mut self, preserve_labels: bool) -> Self {
+        self.preserve_labels = preserve_labels;
+
+        self
+    }
+
+    /// The number of start nodes to try before giving up.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Extract a query from `g`, together with its embedding, where the `i`-th node of the
+    /// query is matched to the `i`-th node of the embedding. Return `None` if no query is
+    /// found within the given attempts.
+    pub fn extract<Id, NL, EL, Ty, G>(&self, g: &G) -> Option<Query<Id, NL, EL, Ty>>
+    where
+        Id: IdType,
+        NL: Hash + Eq + Clone,
+        EL: Hash + Eq + Clone,
+        Ty: GraphType,
+        G: ?Sized + GeneralGraph<Id, NL, EL>,
+    {
+        self.extract_with_rng(&mut thread_rng(), g)
+    }
+
+    /// `extract` drawing random numbers from `rng`.
+    pub fn extract_with_rng<Id, NL, EL, Ty, G, R>(
+        &self,
+        rng: &mut R,
+        g: &G,
+    ) -> Option<Query<Id, NL, EL, Ty>>
+    where
+        Id: IdType,
+        NL: Hash + Eq + Clone,
+        EL: Hash + Eq + Clone,
+        Ty: GraphType,
+        G: ?Sized + GeneralGraph<Id, NL, EL>,
+        R: Rng,
+    {
+        self.try_extract_with_rng(rng, g)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `extract_with_rng`, but return an error if the query is not as directed as
+    /// `g`.
+    pub fn try_extract_with_rng<Id, NL, EL, Ty, G, R>(
+        &self,
+        rng: &mut R,
+        g: &G,
+    ) -> Result<Option<Query<Id, NL, EL, Ty>>>
+    where
+        Id: IdType,
+        NL: Hash + Eq + Clone,
+        EL: Hash + Eq + Clone,
+        Ty: GraphType,
+        G: ?Sized + GeneralGraph<Id, NL, EL>,
+        R: Rng,
+    {
+        if Ty::is_directed() != g.is_directed() {
+            return Err(GraphError::InvalidArgument(
+                "The query must be as directed as the data graph.".to_owned(),
+            ));
+        }
+
+        if self.num_nodes > g.node_count() {
+            return Ok(None);
+        }
+
+        let mut nodes: Vec<Id> = g.node_indices().collect();
+        nodes.sort();
+
+        for _ in 0..self.max_attempts {
+            let start = *rng.choose(&nodes).unwrap();
+
+            let picked = match self.strategy {
+                QueryStrategy::RandomWalk => self.random_walk(rng, g, start),
+                QueryStrategy::Bfs => self.bfs(rng, g, start),
+            };
+
+            if let Some((embedding, tree_edges)) = picked {
+                if let Some(query) = self.build_query(rng, g, &embedding, tree_edges) {
+                    return Ok(Some((query, embedding)));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    // Pick nodes by a random walk from `start`.
+    fn random_walk<Id, NL, EL, G, R>(&self, rng: &mut R, g: &G, start: Id) -> Option<Picked<Id>>
+    where
+        Id: IdType,
+        NL: Hash + Eq,
+        EL: Hash + Eq,
+        G: ?Sized + GeneralGraph<Id, NL, EL>,
+        R: Rng,
+    {
+        let mut embedding = vec![start];
+        let mut tree_edges = Vec::new();
+        let mut current = start;
+
+        for _ in 0..self.num_nodes * 100 {
+            if embedding.len() == self.num_nodes {
+                break;
+            }
+
+            let incident = incident_edges(g, current);
+            let (s, t) = *rng.choose(&incident)?;
+            let next = if s == current { t } else { s };
+
+            if !embedding.contains(&next) {
+                embedding.push(next);
+                tree_edges.push((s, t));
+            }
+
+            current = next;
+        }
+
+        if embedding.len() == self.num_nodes {
+            Some((embedding, tree_edges))
+        } else {
+            None
+        }
+    }
+
+    // Pick nodes by a breadth-first search from `start`.
+    fn bfs<Id, NL, EL, G, R>(&self, rng: &mut R, g: &G, start: Id) -> Option<Picked<Id>>
+    where
+        Id: IdType,
+        NL: Hash + Eq,
+        EL: Hash + Eq,
+        G: ?Sized + GeneralGraph<Id, NL, EL>,
+        R: Rng,
+    {
+        let mut embedding = vec![start];
+        let mut tree_edges = Vec::new();
+        let mut head = 0;
+
+        while head < embedding.len() && embedding.len() < self.num_nodes {
+            let current = embedding[head];
+            head += 1;
+
+            let mut incident = incident_edges(g, current);
+            rng.shuffle(&mut incident);
+
+            for (s, t) in incident {
+                let next = if s == current { t } else { s };
+
+                if embedding.len() < self.num_nodes && !embedding.contains(&next) {
+                    embedding.push(next);
+                    tree_edges.push((s, t));
+                }
+            }
+        }
+
+        if embedding.len() == self.num_nodes {
+            Some((embedding, tree_edges))
+        } else {
+            None
+        }
+    }
+
+    fn build_query<Id, NL, EL, Ty, G, R>(
+        &self,
+        rng: &mut R,
+        g: &G,
+        embedding: &[Id],
+        tree_edges: Vec<(Id, Id)>,
+    ) -> Option<TypedGraphMap<Id, NL, EL, Ty>>
+    where
+        Id: IdType,
+        NL: Hash + Eq + Clone,
+        EL: Hash + Eq + Clone,
+        Ty: GraphType,
+        G: ?Sized + GeneralGraph<Id, NL, EL>,
+        R: Rng,
+    {
+        let positions: HashMap<Id, usize> = embedding
+            .iter()
+            .enumerate()
+            .map(|(i, &id)| (id, i))
+            .collect();
+
+        let mut other_edges = Vec::new();
+
+        for &s in embedding {
+            for t in g.neighbors_iter(s) {
+                let is_new = positions.contains_key(&t)
+                    && (g.is_directed() || s < t)
+                    && !tree_edges.contains(&(s, t))
+                    && (g.is_directed() || !tree_edges.contains(&(t, s)));
+
+                if is_new {
+                    other_edges.push((s, t));
+                }
+            }
+        }
+
+        let num_edges = tree_edges.len() + other_edges.len();
+
+        let edges = match self.density {
+            QueryDensity::Sparse => {
+                rng.shuffle(&mut other_edges);
+
+                // Keep the average degree, `2 * |E| / |V|`, below 3.
+                let max_edges = (3 * self.num_nodes).saturating_sub(1) / 2;
+                let num_others = max_edges.saturating_sub(tree_edges.len());
+
+                let mut edges = tree_edges;
+                edges.extend(other_edges.into_iter().take(num_others));
+
+                edges
+            }
+            QueryDensity::Dense => {
+                if 2 * num_edges < 3 * self.num_nodes {
+                    return None;
+                }
+
+                let mut edges = tree_edges;
+                edges.extend(other_edges);
+
+                edges
+            }
+        };
+
+        let mut query = TypedGraphMap::new();
+
+        for (i, &id) in embedding.iter().enumerate() {
+            let label = if self.preserve_labels {
+                g.get_node_label(id).cloned()
+            } else {
+                None
+            };
+
+            query.add_node(Id::new(i), label);
+        }
+
+        for (s, t) in edges {
+            let label = if self.preserve_labels {
+                g.get_edge_label(s, t).cloned()
+            } else {
+                None
+            };
+
+            query.add_edge(Id::new(positions[&s]), Id::new(positions[&t]), label);
+        }
+
+        Some(query)
+    }
+}
+
+// The edges going out of or into `id`, in both directions for directed graphs.
+fn incident_edges<Id, NL, EL, G>(g: &G, id: Id) -> Vec<(Id, Id)>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    G: ?Sized + GeneralGraph<Id, NL, EL>,
+{
+    let mut edges: Vec<(Id, Id)> = g.neighbors_iter(id).map(|t| (id, t)).collect();
+
+    if let Some(digraph) = g.as_digraph() {
+        edges.extend(digraph.in_neighbors_iter(id).map(|s| (s, id)));
+    }
+
+    edges
+}
@@ -1,73 +1,198 @@
+use std::collections::HashSet;
 use std::hash::Hash;
 
-use rand::seq::sample_iter;
 use rand::{thread_rng, Rng};
 
+use error::{GraphError, Result};
+
 use generic::GraphType;
 use generic::IdType;
-use generic::MutGraphTrait;
+use generic::{GraphTrait, MutGraphTrait};
 
-use graph_gen::general::empty_graph;
-use graph_gen::helper::{complete_edge_pairs, random_edge_label};
+use graph_gen::helper::{add_labeled_edges, complete_edge_pairs, labeled_empty_graph};
+use graph_gen::label::{assign_degree_correlated_labels, Labels};
 use graph_impl::TypedGraphMap;
 
-pub fn random_gnp_graph<Id, NL, EL, Ty>(
+pub fn random_gnp_graph<Id, NL, EL, Ty, NLs, ELs>(
     n: usize,
     p: f32,
-    node_label: Vec<NL>,
-    edge_label: Vec<EL>,
+    node_label: NLs,
+    edge_label: ELs,
 ) -> TypedGraphMap<Id, NL, EL, Ty>
 where
     Id: IdType,
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    random_gnp_graph_with_rng(&mut thread_rng(), n, p, node_label, edge_label)
+}
+
+/// `random_gnp_graph` drawing random numbers from `rng`.
+pub fn random_gnp_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_random_gnp_graph_with_rng(rng, n, p, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `random_gnp_graph_with_rng`, but return an error if `p` is not in `[0,1]`.
+pub fn try_random_gnp_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
 {
     if p < 0f32 || p > 1f32 {
-        panic!("p must be in the range of [0,1]");
+        return Err(GraphError::InvalidArgument(
+            "p must be in the range of [0,1].".to_owned(),
+        ));
     }
 
-    let mut rng = thread_rng();
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
 
-    let mut g = empty_graph::<Id, NL, EL, Ty>(n, node_label, edge_label);
+    let edges = if p == 0f32 {
+        Vec::new()
+    } else if p == 1f32 {
+        complete_edge_pairs::<Ty>(n).collect()
+    } else {
+        sparse_gnp_edges::<Ty, _>(rng, n, p)
+    };
 
-    for (s, d) in complete_edge_pairs::<Ty>(n) {
-        if rng.gen_range(0f32, 1f32) < p {
-            let label = random_edge_label(&mut rng, &g);
-            g.add_edge(Id::new(s), Id::new(d), label);
-        }
-    }
+    add_labeled_edges(rng, &mut g, edges, &node_label, &edge_label);
 
-    g
+    Ok(g)
 }
 
-pub fn random_gnm_graph<Id, NL, EL, Ty>(
+pub fn random_gnm_graph<Id, NL, EL, Ty, NLs, ELs>(
     n: usize,
     m: usize,
-    node_label: Vec<NL>,
-    edge_label: Vec<EL>,
+    node_label: NLs,
+    edge_label: ELs,
 ) -> TypedGraphMap<Id, NL, EL, Ty>
 where
     Id: IdType,
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
 {
-    let mut rng = thread_rng();
+    random_gnm_graph_with_rng(&mut thread_rng(), n, m, node_label, edge_label)
+}
 
-    let mut g = empty_graph::<Id, NL, EL, Ty>(n, node_label, edge_label);
-    let sampled_edges = sample_iter(&mut rng, complete_edge_pairs::<Ty>(n), m);
+/// `random_gnm_graph` drawing random numbers from `rng`.
+pub fn random_gnm_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_random_gnm_graph_with_rng(rng, n, m, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `random_gnm_graph_with_rng`, but return an error if `m` exceeds the number of
+/// node pairs.
+pub fn try_random_gnm_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    m: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    let num_pairs = if Ty::is_directed() {
+        n * n.saturating_sub(1)
+    } else {
+        n * n.saturating_sub(1) / 2
+    };
+
+    if m > num_pairs {
+        return Err(GraphError::InvalidArgument("m is too large.".to_owned()));
+    }
+
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
+
+    // Sample the pairs to keep if they are few, and the pairs to drop otherwise, by
+    // rejecting pairs already sampled, in O(n + m) expected time.
+    let sample_kept = m <= num_pairs / 2;
+    let num_samples = if sample_kept { m } else { num_pairs - m };
+
+    let mut sampled = HashSet::with_capacity(num_samples);
+    let mut edges = Vec::with_capacity(m);
 
-    if let Ok(mut edges) = sampled_edges {
-        for (s, d) in edges.drain(..) {
-            let label = random_edge_label(&mut rng, &g);
-            g.add_edge(Id::new(s), Id::new(d), label);
+    while sampled.len() < num_samples {
+        let s = rng.gen_range(0, n);
+        let d = rng.gen_range(0, n);
+
+        if s == d {
+            continue;
         }
 
-        g
-    } else {
-        panic!("m is too large.");
+        let pair = if Ty::is_directed() {
+            (s, d)
+        } else {
+            (s.min(d), s.max(d))
+        };
+
+        if sampled.insert(pair) && sample_kept {
+            edges.push(pair);
+        }
     }
+
+    if !sample_kept {
+        edges.extend(complete_edge_pairs::<Ty>(n).filter(|pair| !sampled.contains(pair)));
+    }
+
+    add_labeled_edges(rng, &mut g, edges, &node_label, &edge_label);
+
+    Ok(g)
 }
 
 pub fn random_gnp_graph_unlabeled<Id, NL, EL, Ty>(n: usize, p: f32) -> TypedGraphMap<Id, NL, EL, Ty>
@@ -92,3 +217,519 @@ where
 {
     random_gnm_graph(n, m, Vec::new(), Vec::new())
 }
+
+/// Watts–Strogatz small-world graph: each node of a ring lattice is joined to its `k / 2`
+/// nearest neighbors on either side, then each lattice edge `(u, v)` is rewired with
+/// probability `p` to `(u, w)` for a uniformly chosen `w` avoiding self-loops and existing
+/// edges. Only undirected graphs are supported.
+pub fn watts_strogatz_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    watts_strogatz_graph_with_rng(&mut thread_rng(), n, k, p, node_label, edge_label)
+}
+
+/// `watts_strogatz_graph` drawing random numbers from `rng`.
+pub fn watts_strogatz_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_watts_strogatz_graph_with_rng(rng, n, k, p, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `watts_strogatz_graph_with_rng`, but return an error if `p` is not in `[0,1]`,
+/// `k >= n` or the graph is directed.
+pub fn try_watts_strogatz_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if !(0f32..=1f32).contains(&p) {
+        return Err(GraphError::InvalidArgument(
+            "p must be in the range of [0,1].".to_owned(),
+        ));
+    }
+
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = ring_lattice_graph::<Id, NL, EL, Ty, _>(rng, n, k, &node_label, &edge_label)?;
+
+    for j in 1..=k / 2 {
+        for u in 0..n {
+            if rng.gen_range(0f32, 1f32) >= p || g.degree(Id::new(u)) >= n - 1 {
+                continue;
+            }
+
+            let mut w = rng.gen_range(0, n);
+            while w == u || g.has_edge(Id::new(u), Id::new(w)) {
+                w = rng.gen_range(0, n);
+            }
+
+            g.remove_edge(Id::new(u), Id::new((u + j) % n));
+
+            let label = edge_label.draw(rng);
+            g.add_edge(Id::new(u), Id::new(w), label);
+        }
+    }
+
+    assign_degree_correlated_labels(rng, &mut g, &node_label, &edge_label);
+
+    Ok(g)
+}
+
+/// Newman–Watts small-world graph: a ring lattice as in `watts_strogatz_graph`, where for
+/// each lattice edge `(u, v)` a shortcut `(u, w)` is added with probability `p` instead of
+/// being rewired. Only undirected graphs are supported.
+pub fn newman_watts_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    newman_watts_graph_with_rng(&mut thread_rng(), n, k, p, node_label, edge_label)
+}
+
+/// `newman_watts_graph` drawing random numbers from `rng`.
+pub fn newman_watts_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_newman_watts_graph_with_rng(rng, n, k, p, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `newman_watts_graph_with_rng`, but return an error if `p` is not in `[0,1]`,
+/// `k >= n` or the graph is directed.
+pub fn try_newman_watts_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if !(0f32..=1f32).contains(&p) {
+        return Err(GraphError::InvalidArgument(
+            "p must be in the range of [0,1].".to_owned(),
+        ));
+    }
+
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = ring_lattice_graph::<Id, NL, EL, Ty, _>(rng, n, k, &node_label, &edge_label)?;
+
+    for _ in 0..k / 2 {
+        for u in 0..n {
+            if rng.gen_range(0f32, 1f32) >= p || g.degree(Id::new(u)) >= n - 1 {
+                continue;
+            }
+
+            let mut w = rng.gen_range(0, n);
+            while w == u || g.has_edge(Id::new(u), Id::new(w)) {
+                w = rng.gen_range(0, n);
+            }
+
+            let label = edge_label.draw(rng);
+            g.add_edge(Id::new(u), Id::new(w), label);
+        }
+    }
+
+    assign_degree_correlated_labels(rng, &mut g, &node_label, &edge_label);
+
+    Ok(g)
+}
+
+/// Random `d`-regular graph of `n` nodes, sampled by pairing `d` stubs per node while
+/// avoiding self-loops and multi-edges (Steger & Wormald, 1999). Only undirected graphs are
+/// supported.
+pub fn random_regular_graph<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    d: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    random_regular_graph_with_rng(&mut thread_rng(), n, d, node_label, edge_label)
+}
+
+/// `random_regular_graph` drawing random numbers from `rng`.
+pub fn random_regular_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    d: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_random_regular_graph_with_rng(rng, n, d, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `random_regular_graph_with_rng`, but return an error if `d >= n`, `n * d` is odd
+/// or the graph is directed.
+pub fn try_random_regular_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    d: usize,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if Ty::is_directed() {
+        return Err(GraphError::InvalidArgument(
+            "Only undirected graphs are supported.".to_owned(),
+        ));
+    }
+
+    if d >= n || (n * d) & 1 == 1 {
+        return Err(GraphError::InvalidArgument(
+            "d must be less than n and n * d must be even.".to_owned(),
+        ));
+    }
+
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
+
+    let stubs: Vec<usize> = (0..n).flat_map(|u| (0..d).map(move |_| u)).collect();
+
+    let edges = loop {
+        if let Some(edges) = pair_stubs(rng, stubs.clone()) {
+            break edges;
+        }
+    };
+
+    add_labeled_edges(rng, &mut g, edges, &node_label, &edge_label);
+
+    Ok(g)
+}
+
+/// Configuration-model graph whose node `i` has `degree_sequence[i]` stubs, which are
+/// paired uniformly at random. As `TypedGraphMap` is a simple graph, multi-edges are
+/// always erased; self-loops are erased if `erase_self_loops`, and kept otherwise.
+/// In either case the resulting degrees may fall below the given ones. Only undirected
+/// graphs are supported.
+pub fn configuration_model_graph<Id, NL, EL, Ty, NLs, ELs>(
+    degree_sequence: &[usize],
+    erase_self_loops: bool,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    configuration_model_graph_with_rng(
+        &mut thread_rng(),
+        degree_sequence,
+        erase_self_loops,
+        node_label,
+        edge_label,
+    )
+}
+
+/// `configuration_model_graph` drawing random numbers from `rng`.
+pub fn configuration_model_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    degree_sequence: &[usize],
+    erase_self_loops: bool,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_configuration_model_graph_with_rng(
+        rng,
+        degree_sequence,
+        erase_self_loops,
+        node_label,
+        edge_label,
+    )
+    .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `configuration_model_graph_with_rng`, but return an error if the degrees sum up
+/// to an odd number or the graph is directed.
+pub fn try_configuration_model_graph_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    degree_sequence: &[usize],
+    erase_self_loops: bool,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if Ty::is_directed() {
+        return Err(GraphError::InvalidArgument(
+            "Only undirected graphs are supported.".to_owned(),
+        ));
+    }
+
+    if degree_sequence.iter().sum::<usize>() & 1 == 1 {
+        return Err(GraphError::InvalidArgument(
+            "The sum of the degree sequence must be even.".to_owned(),
+        ));
+    }
+
+    let n = degree_sequence.len();
+    let (node_label, edge_label) = (node_label.into(), edge_label.into());
+    let mut g = labeled_empty_graph(rng, n, &node_label, &edge_label);
+
+    let mut stubs: Vec<usize> = degree_sequence
+        .iter()
+        .enumerate()
+        .flat_map(|(u, &d)| (0..d).map(move |_| u))
+        .collect();
+    rng.shuffle(&mut stubs);
+
+    for pair in stubs.chunks(2) {
+        let (s, t) = (pair[0], pair[1]);
+
+        if (s == t && erase_self_loops) || g.has_edge(Id::new(s), Id::new(t)) {
+            continue;
+        }
+
+        let label = edge_label.draw(rng);
+        g.add_edge(Id::new(s), Id::new(t), label);
+    }
+
+    assign_degree_correlated_labels(rng, &mut g, &node_label, &edge_label);
+
+    Ok(g)
+}
+
+fn ring_lattice_graph<Id, NL, EL, Ty, R>(
+    rng: &mut R,
+    n: usize,
+    k: usize,
+    node_label: &Labels<NL>,
+    edge_label: &Labels<EL>,
+) -> Result<TypedGraphMap<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    if Ty::is_directed() {
+        return Err(GraphError::InvalidArgument(
+            "Only undirected graphs are supported.".to_owned(),
+        ));
+    }
+
+    if k >= n {
+        return Err(GraphError::InvalidArgument(
+            "k must be less than n.".to_owned(),
+        ));
+    }
+
+    let mut g = labeled_empty_graph(rng, n, node_label, edge_label);
+
+    for j in 1..=k / 2 {
+        for u in 0..n {
+            let label = edge_label.draw(rng);
+            g.add_edge(Id::new(u), Id::new((u + j) % n), label);
+        }
+    }
+
+    Ok(g)
+}
+
+// Batagelj & Brandes (2005): skip over the pairs between two consecutive edges, whose
+// number follows a geometric distribution, in O(n + m) time.
+fn sparse_gnp_edges<Ty: GraphType, R: Rng>(rng: &mut R, n: usize, p: f32) -> Vec<(usize, usize)> {
+    let n = n as i64;
+    let lp = (1f64 - f64::from(p)).ln();
+    let mut skip = || (1f64 - rng.gen_range(0f64, 1f64)).ln() / lp;
+
+    let mut edges = Vec::new();
+
+    if Ty::is_directed() {
+        let (mut v, mut w) = (0i64, -1i64);
+
+        while v < n {
+            w += 1 + skip() as i64;
+
+            if v == w {
+                w += 1;
+            }
+
+            while v < n && n <= w {
+                w -= n;
+                v += 1;
+
+                if v == w {
+                    w += 1;
+                }
+            }
+
+            if v < n {
+                edges.push((v as usize, w as usize));
+            }
+        }
+    } else {
+        let (mut v, mut w) = (1i64, -1i64);
+
+        while v < n {
+            w += 1 + skip() as i64;
+
+            while w >= v && v < n {
+                w -= v;
+                v += 1;
+            }
+
+            if v < n {
+                edges.push((w as usize, v as usize));
+            }
+        }
+    }
+
+    edges
+}
+
+// Pair up `stubs` into edges without self-loops or multi-edges, re-pairing the stubs of
+// rejected pairs until none is left. Return `None` if the remaining stubs cannot be paired.
+fn pair_stubs<R: Rng>(rng: &mut R, mut stubs: Vec<usize>) -> Option<Vec<(usize, usize)>> {
+    let mut edges = HashSet::new();
+
+    while !stubs.is_empty() {
+        let mut potential = Vec::new();
+
+        rng.shuffle(&mut stubs);
+
+        for pair in stubs.chunks(2) {
+            let (s, t) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+
+            if s != t && edges.insert((s, t)) {
+                continue;
+            }
+
+            potential.push(s);
+            potential.push(t);
+        }
+
+        let suitable = potential.iter().enumerate().any(|(i, &s)| {
+            potential[i + 1..]
+                .iter()
+                .any(|&t| s != t && !edges.contains(&(s.min(t), s.max(t))))
+        });
+
+        if !potential.is_empty() && !suitable {
+            return None;
+        }
+
+        stubs = potential;
+    }
+
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort();
+
+    Some(edges)
+}
@@ -0,0 +1,381 @@
+use std::hash::Hash;
+use std::thread;
+
+use rand::{thread_rng, ChaChaRng, Rng, SeedableRng};
+
+use error::{GraphError, Result};
+use generic::GraphType;
+use generic::IdType;
+
+use graph_impl::static_graph::EdgeVec;
+use graph_impl::TypedStaticGraph;
+
+/// Generator of R-MAT (recursive matrix) graphs, i.e., stochastic Kronecker graphs with a
+/// 2x2 initiator matrix `[[a, b], [c, d]]`, of `2^scale` nodes and `edge_factor * 2^scale`
+/// sampled edges. The graph is built directly into a `TypedStaticGraph`.
+///
+/// By default, duplicate edges and self-loops are removed, so the graph may end up with
+/// fewer edges than sampled. Self-loops are always removed from undirected graphs.
+#[derive(Debug, Clone)]
+pub struct RMatGenerator {
+    scale: usize,
+    edge_factor: usize,
+    probabilities: [f32; 4],
+    noise: f32,
+    permute: bool,
+    dedup: bool,
+    self_loops: bool,
+    threads: usize,
+}
+
+impl RMatGenerator {
+    /// With the initiator of Graph500, i.e., `(0.57, 0.19, 0.19, 0.05)`.
+    pub fn new(scale: usize, edge_factor: usize) -> Self {
+        Self::try_new(scale, edge_factor).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `new`, but return an error if `scale` is not in the range of `[1,63)`, or if
+    /// the number of nodes or sampled edges overflows a `usize`.
+    pub fn try_new(scale: usize, edge_factor: usize) -> Result<Self> {
+        if !(1..63).contains(&scale) {
+            return Err(GraphError::InvalidArgument(
+                "scale must be in the range of [1,63).".to_owned(),
+            ));
+        }
+
+        let num_edges = 1usize
+            .checked_shl(scale as u32)
+            .and_then(|num_nodes| edge_factor.checked_mul(num_nodes));
+
+        if num_edges.is_none() {
+            return Err(GraphError::InvalidArgument(
+                "scale and edge_factor overflow the number of edges.".to_owned(),
+            ));
+        }
+
+        Ok(RMatGenerator {
+            scale,
+            edge_factor,
+            probabilities: [0.57, 0.19, 0.19, 0.05],
+            noise: 0f32,
+            permute: false,
+            dedup: true,
+            self_loops: false,
+            threads: 1,
+        })
+    }
+
+    /// The generator of the Graph500 benchmark, which randomly permutes the node ids.
+    pub fn graph500(scale: usize, edge_factor: usize) -> Self {
+        let mut generator = Self::new(scale, edge_factor);
+        generator.permute = true;
+
+        generator
+    }
+
+    pub fn with_probabilities(self, a: f32, b: f32, c: f32, d: f32) -> Self {
+        self.try_with_probabilities(a, b, c, d)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `with_probabilities`, but return an error if `a`, `b`, `c` and `d` are not
+    /// non-negative or do not sum up to 1.
+    pub fn try_with_probabilities(mut self, a: f32, b: f32, c: f32, d: f32) -> Result<Self> {
+        if a < 0f32 || b < 0f32 || c < 0f32 || d < 0f32 || (a + b + c + d - 1f32).abs() > 1e-4 {
+            return Err(GraphError::InvalidArgument(
+                "a, b, c and d must be non-negative and sum up to 1.".to_owned(),
+            ));
+        }
+
+        self.probabilities = [a, b, c, d];
+
+        Ok(self)
+    }
+
+    /// Perturb the initiator at each level as in the noisy stochastic Kronecker graph of
+    /// Seshadhri et al., which smooths out the oscillating degree distribution of R-MAT.
+    /// `noise` must be at most `min((a + d) / 2, b, c)`.
+    pub fn with_noise(self, noise: f32) -> Self {
+        self.try_with_noise(noise)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `with_noise`, but return an error if `noise` is negative.
+    pub fn try_with_noise(mut self, noise: f32) -> Result<Self> {
+        if noise < 0f32 {
+            return Err(GraphError::InvalidArgument(
+                "noise must be non-negative.".to_owned(),
+            ));
+        }
+
+        self.noise = noise;
+
+        Ok(self)
+    }
+
+    pub fn with_permutation(mut self, permute: bool) -> Self {
+        self.permute = permute;
+
+        self
+    }
+
+    pub fn with_deduplication(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+
+        self
+    }
+
+    pub fn with_self_loops(mut self, self_loops: bool) -> Self {
+        self.self_loops = self_loops;
+
+        self
+    }
+
+    /// Sample the edges on `threads` threads.
+    pub fn with_threads(self, threads: usize) -> Self {
+        self.try_with_threads(threads)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `with_threads`, but return an error if `threads` is zero.
+    pub fn try_with_threads(mut self, threads: usize) -> Result<Self> {
+        if threads < 1 {
+            return Err(GraphError::InvalidArgument(
+                "threads must be positive.".to_owned(),
+            ));
+        }
+
+        self.threads = threads;
+
+        Ok(self)
+    }
+
+    pub fn num_of_nodes(&self) -> usize {
+        1 << self.scale
+    }
+
+    pub fn num_of_sampled_edges(&self) -> usize {
+        self.edge_factor << self.scale
+    }
+
+    pub fn generate<Id, NL, EL, Ty>(&self) -> TypedStaticGraph<Id, NL, EL, Ty>
+    where
+        Id: IdType + Send,
+        NL: Hash + Eq,
+        EL: Hash + Eq,
+        Ty: GraphType,
+    {
+        self.generate_with_rng(&mut thread_rng())
+    }
+
+    /// `generate` drawing random numbers from `rng`. Each thread samples its edges from its
+    /// own generator seeded by `rng`, so the graph depends on the number of threads.
+    pub fn generate_with_rng<Id, NL, EL, Ty, R>(
+        &self,
+        rng: &mut R,
+    ) -> TypedStaticGraph<Id, NL, EL, Ty>
+    where
+        Id: IdType + Send,
+        NL: Hash + Eq,
+        EL: Hash + Eq,
+        Ty: GraphType,
+        R: Rng,
+    {
+        self.try_generate_with_rng(rng)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `generate_with_rng`, but return an error if the nodes do not fit in the id
+    /// type, or if the noise is larger than `min((a + d) / 2, b, c)`.
+    pub fn try_generate_with_rng<Id, NL, EL, Ty, R>(
+        &self,
+        rng: &mut R,
+    ) -> Result<TypedStaticGraph<Id, NL, EL, Ty>>
+    where
+        Id: IdType + Send,
+        NL: Hash + Eq,
+        EL: Hash + Eq,
+        Ty: GraphType,
+        R: Rng,
+    {
+        let num_nodes = self.num_of_nodes();
+
+        if num_nodes > Id::max_value().id() {
+            return Err(GraphError::InvalidArgument(
+                "scale is too large for the id type.".to_owned(),
+            ));
+        }
+
+        let levels = self.levels(rng)?;
+
+        let num_edges = self.num_of_sampled_edges();
+        let chunk = num_edges / self.threads;
+        let remainder = num_edges % self.threads;
+
+        let mut rngs: Vec<ChaChaRng> = (0..self.threads)
+            .map(|_| ChaChaRng::from_seed(&rng.gen::<[u32; 8]>()))
+            .collect();
+
+        let mut edges: Vec<(Id, Id)> = if self.threads == 1 {
+            sample_edges(&mut rngs[0], &levels, num_edges)
+        } else {
+            let levels = &levels;
+
+            thread::scope(|scope| {
+                let handles: Vec<_> = rngs
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(i, rng)| {
+                        let len = if i < remainder { chunk + 1 } else { chunk };
+
+                        scope.spawn(move || sample_edges::<Id, _>(rng, levels, len))
+                    })
+                    .collect();
+
+                let mut edges = Vec::with_capacity(num_edges);
+
+                for handle in handles {
+                    edges.extend(handle.join().unwrap());
+                }
+
+                edges
+            })
+        };
+
+        if self.permute {
+            let mut permutation: Vec<Id> = (0..num_nodes).map(Id::new).collect();
+            rng.shuffle(&mut permutation);
+
+            for edge in &mut edges {
+                *edge = (permutation[edge.0.id()], permutation[edge.1.id()]);
+            }
+        }
+
+        if !self.self_loops || !Ty::is_directed() {
+            edges.retain(|&(s, t)| s != t);
+        }
+
+        if !Ty::is_directed() {
+            let len = edges.len();
+            edges.reserve(len);
+
+            for i in 0..len {
+                let (s, t) = edges[i];
+                edges.push((t, s));
+            }
+        }
+
+        if self.dedup {
+            edges.sort_unstable();
+            edges.dedup();
+        }
+
+        let in_edge_vec = if Ty::is_directed() {
+            let in_edges = edges.iter().map(|&(s, t)| (t, s)).collect();
+
+            Some(EdgeVec::from_edges(num_nodes, in_edges))
+        } else {
+            None
+        };
+
+        Ok(TypedStaticGraph::new(
+            num_nodes,
+            EdgeVec::from_edges(num_nodes, edges),
+            in_edge_vec,
+        ))
+    }
+
+    // The cumulative probabilities of the four quadrants at each level.
+    fn levels<R: Rng>(&self, rng: &mut R) -> Result<Vec<[f32; 4]>> {
+        let [a, b, c, d] = self.probabilities;
+
+        if self.noise > (a + d) / 2f32 || self.noise > b || self.noise > c {
+            return Err(GraphError::InvalidArgument(
+                "noise must be at most min((a + d) / 2, b, c).".to_owned(),
+            ));
+        }
+
+        Ok((0..self.scale)
+            .map(|_| {
+                let mu = if self.noise > 0f32 {
+                    rng.gen_range(-self.noise, self.noise)
+                } else {
+                    0f32
+                };
+
+                if mu == 0f32 {
+                    return [a, a + b, a + b + c, 1f32];
+                }
+
+                // `mu` is only non-zero if `a + d >= 2 * noise > 0`.
+                let a = a - 2f32 * mu * a / (a + d);
+                let b = b + mu;
+                let c = c + mu;
+
+                [a, a + b, a + b + c, 1f32]
+            })
+            .collect())
+    }
+}
+
+fn sample_edges<Id: IdType, R: Rng>(
+    rng: &mut R,
+    levels: &[[f32; 4]],
+    num_edges: usize,
+) -> Vec<(Id, Id)> {
+    let scale = levels.len();
+
+    (0..num_edges)
+        .map(|_| {
+            let (mut s, mut t) = (0, 0);
+
+            for (i, level) in levels.iter().enumerate() {
+                let bit = 1 << (scale - 1 - i);
+                let r = rng.gen_range(0f32, 1f32);
+                let quadrant = level.iter().position(|&p| r < p).unwrap_or(3);
+
+                if quadrant & 1 != 0 {
+                    t |= bit;
+                }
+
+                if quadrant & 2 != 0 {
+                    s |= bit;
+                }
+            }
+
+            (Id::new(s), Id::new(t))
+        })
+        .collect()
+}
+
+pub fn rmat_graph<Id, NL, EL, Ty>(
+    scale: usize,
+    edge_factor: usize,
+    a: f32,
+    b: f32,
+    c: f32,
+    d: f32,
+) -> TypedStaticGraph<Id, NL, EL, Ty>
+where
+    Id: IdType + Send,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+{
+    RMatGenerator::new(scale, edge_factor)
+        .with_probabilities(a, b, c, d)
+        .generate()
+}
+
+pub fn graph500_graph<Id, NL, EL, Ty>(
+    scale: usize,
+    edge_factor: usize,
+) -> TypedStaticGraph<Id, NL, EL, Ty>
+where
+    Id: IdType + Send,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+{
+    RMatGenerator::graph500(scale, edge_factor).generate()
+}
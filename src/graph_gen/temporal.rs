@@ -0,0 +1,286 @@
+//! Timestamped edge streams, for streaming subgraph matching.
+
+use std::hash::Hash;
+
+use rand::{thread_rng, Rng};
+
+use error::{GraphError, Result};
+
+use generic::GeneralGraph;
+use generic::GraphType;
+use generic::IdType;
+
+use graph_gen::label::Labels;
+use graph_impl::TypedTemporalGraph;
+use map::SetMap;
+
+/// Forest-fire graph (Leskovec et al., 2005) of `n` nodes, as a stream in which node `i`
+/// arrives at time `i` with all its edges. Every new node links to a random ambassador,
+/// then recursively "burns" a geometric number of the out-links, of mean
+/// `p_forward / (1 - p_forward)`, and of the in-links, of mean
+/// `p_backward / (1 - p_backward)`, of the nodes it has linked to, and links to them too.
+/// The resulting graph densifies and its diameter shrinks over time. For undirected graphs,
+/// all links are burnt with `p_forward`.
+pub fn forest_fire_stream<Id, NL, EL, Ty, NLs, ELs>(
+    n: usize,
+    p_forward: f32,
+    p_backward: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedTemporalGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+{
+    forest_fire_stream_with_rng(
+        &mut thread_rng(),
+        n,
+        p_forward,
+        p_backward,
+        node_label,
+        edge_label,
+    )
+}
+
+/// `forest_fire_stream` drawing random numbers from `rng`.
+pub fn forest_fire_stream_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    p_forward: f32,
+    p_backward: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> TypedTemporalGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    try_forest_fire_stream_with_rng(rng, n, p_forward, p_backward, node_label, edge_label)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `forest_fire_stream_with_rng`, but return an error if `p_forward` or
+/// `p_backward` is not in `[0,1)`.
+pub fn try_forest_fire_stream_with_rng<Id, NL, EL, Ty, NLs, ELs, R>(
+    rng: &mut R,
+    n: usize,
+    p_forward: f32,
+    p_backward: f32,
+    node_label: NLs,
+    edge_label: ELs,
+) -> Result<TypedTemporalGraph<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    NLs: Into<Labels<NL>>,
+    ELs: Into<Labels<EL>>,
+    R: Rng,
+{
+    if !(0f32..1f32).contains(&p_forward) || !(0f32..1f32).contains(&p_backward) {
+        return Err(GraphError::InvalidArgument(
+            "p_forward and p_backward must be in the range of [0,1).".to_owned(),
+        ));
+    }
+
+    let (node_label, edge_label): (Labels<NL>, Labels<EL>) = (node_label.into(), edge_label.into());
+
+    // The out- and in-neighbors of every node, which are the same if undirected.
+    let mut out_links: Vec<Vec<usize>> = Vec::with_capacity(n);
+    let mut in_links: Vec<Vec<usize>> = Vec::with_capacity(n);
+
+    // The stream, with labels unless `DegreeCorrelated`, as the degrees are not known yet.
+    let mut node_labels = Vec::with_capacity(n);
+    let mut edges = Vec::new();
+
+    for v in 0..n {
+        node_labels.push(node_label.draw(rng));
+
+        out_links.push(Vec::new());
+        in_links.push(Vec::new());
+
+        if v == 0 {
+            continue;
+        }
+
+        let mut burnt = vec![false; v];
+        let mut targets = Vec::new();
+
+        let ambassador = rng.gen_range(0, v);
+        burnt[ambassador] = true;
+        let mut frontier = vec![ambassador];
+
+        while let Some(w) = frontier.pop() {
+            targets.push(w);
+
+            let mut links: Vec<usize> = burn(rng, &out_links[w], &burnt, p_forward);
+
+            if Ty::is_directed() {
+                links.extend(burn(rng, &in_links[w], &burnt, p_backward));
+            }
+
+            for x in links {
+                if !burnt[x] {
+                    burnt[x] = true;
+                    frontier.push(x);
+                }
+            }
+        }
+
+        targets.sort();
+
+        for w in targets {
+            edges.push((v, w, edge_label.draw(rng)));
+
+            out_links[v].push(w);
+
+            if Ty::is_directed() {
+                in_links[w].push(v);
+            } else {
+                out_links[w].push(v);
+            }
+        }
+    }
+
+    let degree = |v: usize| {
+        if Ty::is_directed() {
+            out_links[v].len() + in_links[v].len()
+        } else {
+            out_links[v].len()
+        }
+    };
+
+    if node_label.is_degree_correlated() {
+        let degrees: Vec<usize> = (0..n).map(&degree).collect();
+        node_labels = node_label.draw_by_degree(rng, &degrees);
+    }
+
+    if edge_label.is_degree_correlated() {
+        let degrees: Vec<usize> = edges
+            .iter()
+            .map(|&(v, w, _)| degree(v) + degree(w))
+            .collect();
+
+        for (edge, label) in edges
+            .iter_mut()
+            .zip(edge_label.draw_by_degree(rng, &degrees))
+        {
+            edge.2 = label;
+        }
+    }
+
+    let mut g = TypedTemporalGraph::with_label_map(
+        SetMap::from_vec(node_label.get_labels().to_vec()),
+        SetMap::from_vec(edge_label.get_labels().to_vec()),
+    );
+
+    for (v, label) in node_labels.into_iter().enumerate() {
+        g.add_node(Id::new(v), label);
+    }
+
+    for (v, w, label) in edges {
+        g.add_edge(Id::new(v), Id::new(w), v as u64, label);
+    }
+
+    Ok(g)
+}
+
+// Pick a geometric number of the unburnt `links`, of mean `p / (1 - p)`.
+fn burn<R: Rng>(rng: &mut R, links: &[usize], burnt: &[bool], p: f32) -> Vec<usize> {
+    let mut unburnt: Vec<usize> = links.iter().cloned().filter(|&x| !burnt[x]).collect();
+    unburnt.sort();
+    unburnt.dedup();
+
+    let mut num_links = 0;
+    while num_links < unburnt.len() && rng.gen_range(0f32, 1f32) < p {
+        num_links += 1;
+    }
+
+    rng.shuffle(&mut unburnt);
+    unburnt.truncate(num_links);
+
+    unburnt
+}
+
+/// The edges of `g`, with their labels, as a stream in random order, the `i`-th edge
+/// arriving at time `i`. Every node of `g` is kept, with its label, and the label-id
+/// mapping of `g` is kept too.
+pub fn shuffled_edge_stream<Id, NL, EL, Ty, G>(g: &G) -> TypedTemporalGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    G: ?Sized + GeneralGraph<Id, NL, EL>,
+{
+    shuffled_edge_stream_with_rng(&mut thread_rng(), g)
+}
+
+/// `shuffled_edge_stream` drawing random numbers from `rng`.
+pub fn shuffled_edge_stream_with_rng<Id, NL, EL, Ty, G, R>(
+    rng: &mut R,
+    g: &G,
+) -> TypedTemporalGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    G: ?Sized + GeneralGraph<Id, NL, EL>,
+    R: Rng,
+{
+    try_shuffled_edge_stream_with_rng(rng, g).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `shuffled_edge_stream_with_rng`, but return an error if the stream is not as
+/// directed as `g`.
+pub fn try_shuffled_edge_stream_with_rng<Id, NL, EL, Ty, G, R>(
+    rng: &mut R,
+    g: &G,
+) -> Result<TypedTemporalGraph<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    G: ?Sized + GeneralGraph<Id, NL, EL>,
+    R: Rng,
+{
+    if Ty::is_directed() != g.is_directed() {
+        return Err(GraphError::InvalidArgument(
+            "The stream must be as directed as the graph.".to_owned(),
+        ));
+    }
+
+    let mut stream = TypedTemporalGraph::with_label_map(
+        g.get_node_label_map().clone(),
+        g.get_edge_label_map().clone(),
+    );
+
+    let mut nodes: Vec<Id> = g.node_indices().collect();
+    nodes.sort();
+
+    for id in nodes {
+        stream.add_node(id, g.get_node_label(id).cloned());
+    }
+
+    let mut edges: Vec<(Id, Id)> = g.edge_indices().collect();
+    edges.sort();
+    rng.shuffle(&mut edges);
+
+    for (timestamp, (s, t)) in edges.into_iter().enumerate() {
+        stream.add_edge(s, t, timestamp as u64, g.get_edge_label(s, t).cloned());
+    }
+
+    Ok(stream)
+}
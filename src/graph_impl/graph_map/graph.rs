@@ -1,16 +1,21 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{btree_set, hash_map, HashMap};
 use std::hash::Hash;
+use std::iter::Cloned;
 use std::marker::PhantomData;
 
+use error::{GraphError, Result};
+
 use generic::GraphType;
 use generic::Iter;
 use generic::MutMapTrait;
 use generic::{DefaultId, IdType};
 use generic::{DefaultTy, Directed, Undirected};
+use generic::{DiGraphIterTrait, GraphIterTrait};
+use generic::{DuplicatePolicy, InsertStats};
 use generic::{DiGraphTrait, GeneralGraph, GraphLabelTrait, GraphTrait, MutGraphLabelTrait,
               MutGraphTrait, UnGraphTrait};
-use generic::{EdgeType, MutEdgeTrait, MutNodeTrait};
+use generic::{EdgeTrait, EdgeType, MutEdgeTrait, MutNodeTrait};
 use generic::{MutNodeMapTrait, NodeMapTrait, NodeType};
 
 use graph_impl::Graph;
@@ -125,11 +130,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> TypedGraphMap<Id,
     //    pub fn from_edges(edges: impl IntoIterator<Item = (Id, Id)>) -> Self {
     pub fn from_edges<I: IntoIterator<Item = (Id, Id)>>(edges: I) -> Self {
         let mut g = TypedGraphMap::new();
-        for (src, dst) in edges {
-            g.add_node(src, None);
-            g.add_node(dst, None);
-            g.add_edge(src, dst, None);
-        }
+        let edges = edges.into_iter().map(|(src, dst)| (src, dst, None));
+
+        // Ignoring duplicates never fails.
+        g.extend_edges(edges, DuplicatePolicy::Ignore).unwrap();
 
         g
     }
@@ -151,6 +155,40 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> TypedGraphMap<Id,
 
         (start, target)
     }
+
+    // Add a node that is not in the graph yet.
+    fn insert_node(&mut self, id: Id, label_id: Option<Id>) {
+        self.node_map.insert(id, NodeMap::new(id, label_id));
+        match self.max_id {
+            Some(i) => {
+                if i < id {
+                    self.max_id = Some(id)
+                }
+            }
+            None => self.max_id = Some(id),
+        }
+    }
+
+    // Add an edge that is not in the graph yet, with `start` and `target` already swapped.
+    fn insert_edge(&mut self, start: Id, target: Id, label_id: Option<Id>) {
+        if !self.has_node(start) {
+            self.insert_node(start, None);
+        }
+        if !self.has_node(target) {
+            self.insert_node(target, None);
+        }
+
+        self.get_node_mut(start).unwrap().add_edge(target);
+
+        if self.is_directed() {
+            self.get_node_mut(target).unwrap().add_in_edge(start);
+        } else if start != target {
+            self.get_node_mut(target).unwrap().add_edge(start);
+        }
+
+        self.edge_map
+            .insert((start, target), Edge::new(start, target, label_id));
+    }
 }
 
 impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> MutGraphTrait<Id, NL, EL>
@@ -176,16 +214,7 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> MutGraphTrait<Id,
             return false;
         }
 
-        let new_node = NodeMap::new(id, label_id);
-        self.node_map.insert(id, new_node);
-        match self.max_id {
-            Some(i) => {
-                if i < id {
-                    self.max_id = Some(id)
-                }
-            }
-            None => self.max_id = Some(id),
-        }
+        self.insert_node(id, label_id);
 
         true
     }
@@ -241,23 +270,7 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> MutGraphTrait<Id,
             return false;
         }
 
-        if !self.has_node(start) {
-            self.add_node(start, None);
-        }
-        if !self.has_node(target) {
-            self.add_node(target, None);
-        }
-
-        self.get_node_mut(start).unwrap().add_edge(target);
-
-        if self.is_directed() {
-            self.get_node_mut(target).unwrap().add_in_edge(start);
-        } else if start != target {
-            self.get_node_mut(target).unwrap().add_edge(start);
-        }
-
-        let new_edge = Edge::new(start, target, label_id);
-        self.edge_map.insert((start, target), new_edge);
+        self.insert_edge(start, target, label_id);
 
         true
     }
@@ -291,6 +304,77 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> MutGraphTrait<Id,
     fn edges_mut<'a>(&'a mut self) -> Iter<'a, &mut Self::E> {
         Iter::new(Box::new(self.edge_map.values_mut()))
     }
+
+    fn extend_nodes<I>(&mut self, nodes: I, policy: DuplicatePolicy) -> Result<InsertStats>
+    where
+        I: IntoIterator<Item = (Id, Option<NL>)>,
+    {
+        let nodes = nodes.into_iter();
+        self.node_map.reserve(nodes.size_hint().0);
+
+        let mut stats = InsertStats::default();
+
+        for (id, label) in nodes {
+            if !self.has_node(id) {
+                let label_id = label.map(|x| Id::new(self.node_label_map.add_item(x)));
+                self.insert_node(id, label_id);
+                stats.inserted += 1;
+
+                continue;
+            }
+
+            match policy {
+                DuplicatePolicy::Ignore => stats.ignored += 1,
+                DuplicatePolicy::Overwrite => {
+                    let label_id = label.map(|x| Id::new(self.node_label_map.add_item(x)));
+                    self.get_node_mut(id).unwrap().set_label_id(label_id);
+                    stats.overwritten += 1;
+                }
+                DuplicatePolicy::Error => return Err(GraphError::DuplicateNode(id.id())),
+            }
+        }
+
+        Ok(stats)
+    }
+
+    fn extend_edges<I>(&mut self, edges: I, policy: DuplicatePolicy) -> Result<InsertStats>
+    where
+        I: IntoIterator<Item = (Id, Id, Option<EL>)>,
+    {
+        let edges = edges.into_iter();
+        self.edge_map.reserve(edges.size_hint().0);
+
+        let mut stats = InsertStats::default();
+
+        for (start, target, label) in edges {
+            let (start, target) = self.swap_edge(start, target);
+
+            if !self.has_edge(start, target) {
+                let label_id = label.map(|x| Id::new(self.edge_label_map.add_item(x)));
+                self.insert_edge(start, target, label_id);
+                stats.inserted += 1;
+
+                continue;
+            }
+
+            match policy {
+                DuplicatePolicy::Ignore => stats.ignored += 1,
+                DuplicatePolicy::Overwrite => {
+                    let label_id = label.map(|x| Id::new(self.edge_label_map.add_item(x)));
+                    self.edge_map
+                        .get_mut(&(start, target))
+                        .unwrap()
+                        .set_label_id(label_id);
+                    stats.overwritten += 1;
+                }
+                DuplicatePolicy::Error => {
+                    return Err(GraphError::DuplicateEdge(start.id(), target.id()))
+                }
+            }
+        }
+
+        Ok(stats)
+    }
 }
 
 impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> GraphTrait<Id>
@@ -384,6 +468,19 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> GraphTrait<Id>
         }
     }
 
+    fn neighbors_with_labels<'a>(&'a self, id: Id) -> Iter<'a, (Id, Option<Id>)>
+    where
+        Id: 'a,
+    {
+        match self.node_map.get(&id) {
+            Some(node) => Iter::new(Box::new(node.neighbor_set().iter().map(move |&t| {
+                let edge = &self.edge_map[&self.swap_edge(id, t)];
+                (t, edge.get_label_id())
+            }))),
+            None => panic!("Node {} do not exist.", id),
+        }
+    }
+
     //    fn get_node_label_id(&self, node_id: Id) -> Option<Id> {
     //        match self.get_node(node_id) {
     //            NodeType::NodeMap(node) => node.get_label_id(),
@@ -487,6 +584,56 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> DiGraphTrait<Id> for TypedDiGraph
             _ => panic!("Unknown error."),
         }
     }
+
+    fn in_neighbors_with_labels<'a>(&'a self, id: Id) -> Iter<'a, (Id, Option<Id>)>
+    where
+        Id: 'a,
+    {
+        match self.node_map.get(&id) {
+            Some(node) => Iter::new(Box::new(
+                node.in_neighbor_set()
+                    .iter()
+                    .map(move |&s| (s, self.edge_map[&(s, id)].get_label_id())),
+            )),
+            None => panic!("Node {} do not exist.", id),
+        }
+    }
+}
+
+impl<'a, Id: 'a + IdType, NL: 'a + Hash + Eq, EL: 'a + Hash + Eq, Ty: 'a + GraphType>
+    GraphIterTrait<'a, Id> for TypedGraphMap<Id, NL, EL, Ty>
+{
+    type NodeIndices = Cloned<hash_map::Keys<'a, Id, NodeMap<Id>>>;
+    type EdgeIndices = Cloned<hash_map::Keys<'a, (Id, Id), Edge<Id>>>;
+    type Neighbors = Cloned<btree_set::Iter<'a, Id>>;
+
+    fn iter_node_indices(&'a self) -> Self::NodeIndices {
+        self.node_map.keys().cloned()
+    }
+
+    fn iter_edge_indices(&'a self) -> Self::EdgeIndices {
+        self.edge_map.keys().cloned()
+    }
+
+    fn iter_neighbors(&'a self, id: Id) -> Self::Neighbors {
+        match self.node_map.get(&id) {
+            Some(node) => node.neighbor_set().iter().cloned(),
+            None => panic!("Node {} do not exist.", id),
+        }
+    }
+}
+
+impl<'a, Id: 'a + IdType, NL: 'a + Hash + Eq, EL: 'a + Hash + Eq> DiGraphIterTrait<'a, Id>
+    for TypedDiGraphMap<Id, NL, EL>
+{
+    type InNeighbors = Cloned<btree_set::Iter<'a, Id>>;
+
+    fn iter_in_neighbors(&'a self, id: Id) -> Self::InNeighbors {
+        match self.node_map.get(&id) {
+            Some(node) => node.in_neighbor_set().iter().cloned(),
+            None => panic!("Node {} do not exist.", id),
+        }
+    }
 }
 
 impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> GeneralGraph<Id, NL, EL>
@@ -27,6 +27,16 @@ impl<Id: IdType> NodeMap<Id> {
             //            in_edges: HashSet::<Id>::new(),
         }
     }
+
+    /// The neighbors, in ascending order.
+    pub fn neighbor_set(&self) -> &BTreeSet<Id> {
+        &self.edges
+    }
+
+    /// The in-neighbors, in ascending order.
+    pub fn in_neighbor_set(&self) -> &BTreeSet<Id> {
+        &self.in_edges
+    }
 }
 
 impl<Id: IdType> NodeTrait<Id> for NodeMap<Id> {
@@ -1,5 +1,6 @@
 pub mod graph_map;
 pub mod static_graph;
+pub mod temporal_graph;
 
 pub use graph_impl::graph_map::Edge;
 pub use graph_impl::graph_map::{DiGraphMap, GraphMap, UnGraphMap};
@@ -8,7 +9,13 @@ pub use graph_impl::static_graph::{DiStaticGraph, StaticGraph, UnStaticGraph};
 
 pub use graph_impl::graph_map::{TypedDiGraphMap, TypedGraphMap, TypedUnGraphMap};
 pub use graph_impl::static_graph::{TypedDiStaticGraph, TypedStaticGraph, TypedUnStaticGraph};
+pub use graph_impl::temporal_graph::TimedEdge;
+pub use graph_impl::temporal_graph::{DiTemporalGraph, TemporalGraph, UnTemporalGraph};
+pub use graph_impl::temporal_graph::{
+    TypedDiTemporalGraph, TypedTemporalGraph, TypedUnTemporalGraph,
+};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Graph {
     GraphMap,
     StaticGraph,
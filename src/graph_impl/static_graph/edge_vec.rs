@@ -1,3 +1,4 @@
+use error::{GraphError, Result};
 use generic::IdType;
 
 /// With the node indexed from 0 .. num_nodes - 1, we can maintain the edges in a compact way,
@@ -35,6 +36,34 @@ impl<Id: IdType> EdgeVec<Id> {
         }
     }
 
+    /// Build an `EdgeVec` of nodes `0 .. num_nodes` from a list of `(start, target)` pairs.
+    /// The pairs are sorted so that every neighbour list is ordered; duplicates are kept.
+    pub fn from_edges(num_nodes: usize, mut edges: Vec<(Id, Id)>) -> Self {
+        edges.sort_unstable();
+
+        let offsets = compute_offsets(num_nodes, edges.iter().map(|&(s, _)| s));
+        let targets = edges.into_iter().map(|(_, t)| t).collect();
+
+        EdgeVec::new(offsets, targets)
+    }
+
+    /// Same as `from_edges`, but with `(start, target, label)` triples.
+    /// Duplicated pairs keep their relative order.
+    pub fn from_labeled_edges(num_nodes: usize, mut edges: Vec<(Id, Id, Id)>) -> Self {
+        edges.sort_by_key(|&(s, t, _)| (s, t));
+
+        let offsets = compute_offsets(num_nodes, edges.iter().map(|&(s, _, _)| s));
+        let mut targets = Vec::with_capacity(edges.len());
+        let mut labels = Vec::with_capacity(edges.len());
+
+        for (_, t, l) in edges {
+            targets.push(t);
+            labels.push(l);
+        }
+
+        EdgeVec::with_labels(offsets, targets, labels)
+    }
+
     pub fn clear(&mut self) {
         self.offsets.clear();
         self.edges.clear();
@@ -88,6 +117,28 @@ impl<Id: IdType> EdgeVec<Id> {
         &self.edges[start..end]
     }
 
+    /// The labels of the edges from `node`, aligned with `neighbors(node)`, or `None` if the
+    /// edges are unlabeled.
+    pub fn neighbor_labels(&self, node: Id) -> Option<&[Id]> {
+        assert!(self.valid_node(node));
+        let start = self.offsets[node.id()].id();
+        let end = self.offsets[node.id() + 1].id();
+
+        match self.labels {
+            Some(ref labels) => Some(&labels[start..end]),
+            None => None,
+        }
+    }
+
+    /// The neighbors of `node` together with the label ids of the edges to them.
+    pub fn neighbors_with_labels(&self, node: Id) -> LabeledNeighbors<'_, Id> {
+        LabeledNeighbors {
+            neighbors: self.neighbors(node),
+            labels: self.neighbor_labels(node),
+            index: 0,
+        }
+    }
+
     pub fn num_of_neighbors(&self, node: Id) -> usize {
         assert!(self.valid_node(node));
         let start = self.offsets[node.id()].id();
@@ -96,6 +147,24 @@ impl<Id: IdType> EdgeVec<Id> {
         end - start
     }
 
+    /// Same as `neighbors`, but return an error if `node` is out of range.
+    pub fn try_neighbors(&self, node: Id) -> Result<&[Id]> {
+        if !self.valid_node(node) {
+            return Err(GraphError::NodeNotFound(node.id()));
+        }
+
+        Ok(self.neighbors(node))
+    }
+
+    /// Same as `num_of_neighbors`, but return an error if `node` is out of range.
+    pub fn try_num_of_neighbors(&self, node: Id) -> Result<usize> {
+        if !self.valid_node(node) {
+            return Err(GraphError::NodeNotFound(node.id()));
+        }
+
+        Ok(self.num_of_neighbors(node))
+    }
+
     pub fn degree(&self, node: Id) -> usize {
         // self.neighbors(node).len()
         self.num_of_neighbors(node)
@@ -116,6 +185,19 @@ impl<Id: IdType> EdgeVec<Id> {
         }
     }
 
+    /// The inverse of `find_edge_index`: the `(start, target)` of the edge at `index` of the
+    /// edge vector, if `index` is in range.
+    pub fn find_edge(&self, index: usize) -> Option<(Id, Id)> {
+        if index >= self.edges.len() {
+            return None;
+        }
+
+        // The last node whose neighbors start at or before `index`.
+        let start = self.offsets.partition_point(|&offset| offset <= index) - 1;
+
+        Some((Id::new(start), self.edges[index]))
+    }
+
     pub fn has_edge(&self, start: Id, target: Id) -> bool {
         self.find_edge_index(start, target).is_some()
     }
@@ -133,6 +215,82 @@ impl<Id: IdType> EdgeVec<Id> {
         }
     }
 
+    /// Check that this is a valid edge vector of `num_nodes` nodes: the offsets start from 0,
+    /// never decrease and end at the number of edges, every neighbor list is sorted and
+    /// within `0 .. num_nodes`, and the labels, if any, are as many as the edges.
+    pub fn validate(&self, num_nodes: usize) -> Result<()> {
+        if self.offsets.len() != num_nodes + 1 {
+            return Err(GraphError::InvalidGraph(format!(
+                "expect {} offsets for {} nodes, found {}.",
+                num_nodes + 1,
+                num_nodes,
+                self.offsets.len()
+            )));
+        }
+
+        if self.offsets[0] != 0 {
+            return Err(GraphError::InvalidGraph(format!(
+                "the offsets start from {} instead of 0.",
+                self.offsets[0]
+            )));
+        }
+
+        for node in 0..num_nodes {
+            let (start, end) = (self.offsets[node], self.offsets[node + 1]);
+
+            if start > end {
+                return Err(GraphError::InvalidGraph(format!(
+                    "the offsets of node {} decrease from {} to {}.",
+                    node, start, end
+                )));
+            }
+
+            if end > self.edges.len() {
+                return Err(GraphError::InvalidGraph(format!(
+                    "the offsets of node {} exceed the {} edges.",
+                    node,
+                    self.edges.len()
+                )));
+            }
+
+            let neighbors = &self.edges[start..end];
+
+            if let Some(neighbor) = neighbors.iter().find(|n| n.id() >= num_nodes) {
+                return Err(GraphError::InvalidGraph(format!(
+                    "neighbor {} of node {} is out of range.",
+                    neighbor, node
+                )));
+            }
+
+            if neighbors.windows(2).any(|pair| pair[0] > pair[1]) {
+                return Err(GraphError::InvalidGraph(format!(
+                    "the neighbors of node {} are not sorted.",
+                    node
+                )));
+            }
+        }
+
+        if self.offsets[num_nodes] != self.edges.len() {
+            return Err(GraphError::InvalidGraph(format!(
+                "the offsets end at {}, but there are {} edges.",
+                self.offsets[num_nodes],
+                self.edges.len()
+            )));
+        }
+
+        if let Some(ref labels) = self.labels {
+            if labels.len() != self.edges.len() {
+                return Err(GraphError::InvalidGraph(format!(
+                    "{} labels for {} edges.",
+                    labels.len(),
+                    self.edges.len()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     // Verify whether a given `node` is a valid node id.
     // Suppose the maximum node id is `m`, then we must have offsets[m+1], therefore
     // given a node, we must have `node <= m < offsets.len - 1`
@@ -146,3 +304,50 @@ impl<Id: IdType> Default for EdgeVec<Id> {
         EdgeVec::new(Vec::new(), Vec::new())
     }
 }
+
+/// An iterator over `(neighbor, label_id)` pairs, where `label_id` is `None` if the edges
+/// are unlabeled, or if the edge has no label, i.e., its label id is `Id::max_value()`.
+pub struct LabeledNeighbors<'a, Id: 'a + IdType> {
+    neighbors: &'a [Id],
+    labels: Option<&'a [Id]>,
+    index: usize,
+}
+
+impl<'a, Id: 'a + IdType> Iterator for LabeledNeighbors<'a, Id> {
+    type Item = (Id, Option<Id>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let neighbor = *self.neighbors.get(self.index)?;
+        let label = self
+            .labels
+            .map(|labels| labels[self.index])
+            .filter(|&label| label != Id::max_value());
+        self.index += 1;
+
+        Some((neighbor, label))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.neighbors.len() - self.index;
+
+        (len, Some(len))
+    }
+}
+
+/// Compute `offsets` from the (sorted) starting nodes of all edges.
+fn compute_offsets<Id: IdType, I: Iterator<Item = Id>>(num_nodes: usize, starts: I) -> Vec<usize> {
+    let mut offsets = vec![0; num_nodes + 1];
+
+    for s in starts {
+        assert!(s.id() < num_nodes, "Node {} is out of range.", s);
+        offsets[s.id() + 1] += 1;
+    }
+
+    let mut sum = 0;
+    for offset in offsets.iter_mut() {
+        sum += *offset;
+        *offset = sum;
+    }
+
+    offsets
+}
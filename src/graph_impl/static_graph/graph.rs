@@ -1,12 +1,18 @@
 use std::borrow::Cow;
 use std::hash::Hash;
+use std::iter::{Cloned, Map};
 use std::marker::PhantomData;
 use std::mem::replace;
+use std::ops::Range;
+use std::slice;
+
+use error::{GraphError, Result};
 
 use generic::Iter;
 use generic::map::MapTrait;
 use generic::{DefaultId, IdType};
 use generic::{DefaultTy, Directed, GraphType, Undirected};
+use generic::{DiGraphIterTrait, GraphIterTrait};
 use generic::{DiGraphTrait, GeneralGraph, GraphLabelTrait, GraphTrait, UnGraphTrait};
 use generic::{EdgeType, NodeType};
 
@@ -14,7 +20,7 @@ use map::SetMap;
 
 use graph_impl::Edge;
 use graph_impl::Graph;
-use graph_impl::static_graph::edge_vec::EdgeVec;
+use graph_impl::static_graph::edge_vec::{EdgeVec, LabeledNeighbors};
 use graph_impl::static_graph::node::StaticNode;
 
 pub type TypedUnStaticGraph<Id, NL, EL = NL> = TypedStaticGraph<Id, NL, EL, Undirected>;
@@ -126,6 +132,173 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> TypedStaticGraph<I
         }
     }
 
+    /// Same as `new`, but return an error if the graph is invalid, as per `validate`.
+    pub fn try_new(
+        num_nodes: usize,
+        edges: EdgeVec<Id>,
+        in_edges: Option<EdgeVec<Id>>,
+    ) -> Result<Self> {
+        let num_edges = if Ty::is_directed() {
+            edges.len()
+        } else {
+            edges.len() >> 1
+        };
+
+        Self::try_from_raw(
+            num_nodes,
+            num_edges,
+            edges,
+            in_edges,
+            None,
+            SetMap::new(),
+            SetMap::new(),
+        )
+    }
+
+    /// Same as `with_labels`, but return an error if the graph is invalid, as per `validate`.
+    pub fn try_with_labels(
+        num_nodes: usize,
+        edges: EdgeVec<Id>,
+        in_edges: Option<EdgeVec<Id>>,
+        labels: Vec<Id>,
+        node_label_map: SetMap<NL>,
+        edge_label_map: SetMap<EL>,
+    ) -> Result<Self> {
+        let num_edges = if Ty::is_directed() {
+            edges.len()
+        } else {
+            edges.len() >> 1
+        };
+
+        Self::try_from_raw(
+            num_nodes,
+            num_edges,
+            edges,
+            in_edges,
+            Some(labels),
+            node_label_map,
+            edge_label_map,
+        )
+    }
+
+    /// Same as `from_raw`, but return an error if the graph is invalid, as per `validate`.
+    pub fn try_from_raw(
+        num_nodes: usize,
+        num_edges: usize,
+        edge_vec: EdgeVec<Id>,
+        in_edge_vec: Option<EdgeVec<Id>>,
+        labels: Option<Vec<Id>>,
+        node_label_map: SetMap<NL>,
+        edge_label_map: SetMap<EL>,
+    ) -> Result<Self> {
+        let g = TypedStaticGraph {
+            num_nodes,
+            num_edges,
+            edge_vec,
+            in_edge_vec,
+            labels,
+            node_label_map,
+            edge_label_map,
+            graph_type: PhantomData,
+        };
+
+        g.validate()?;
+
+        Ok(g)
+    }
+
+    /// Check the consistency of the graph, e.g., after deserializing it. Besides the checks
+    /// of `EdgeVec::validate` on both edge vectors, the in-edges of a directed graph must be
+    /// the reverse of its edges, an undirected graph must hold every edge in both
+    /// directions, and there must be a label for every node if any. Every node or edge
+    /// label id must be in its label map, unless it is `Id::max_value()` for no label.
+    pub fn validate(&self) -> Result<()> {
+        self.edge_vec.validate(self.num_nodes)?;
+
+        if Ty::is_directed() {
+            let in_edge_vec = match self.in_edge_vec {
+                Some(ref in_edge_vec) => in_edge_vec,
+                None => {
+                    return Err(GraphError::InvalidGraph(
+                        "a directed graph must have in-edges.".to_owned(),
+                    ))
+                }
+            };
+
+            in_edge_vec.validate(self.num_nodes)?;
+
+            if in_edge_vec.len() != self.edge_vec.len() {
+                return Err(GraphError::InvalidGraph(format!(
+                    "{} in-edges for {} edges.",
+                    in_edge_vec.len(),
+                    self.edge_vec.len()
+                )));
+            }
+
+            for (s, t) in self.edge_indices() {
+                if !in_edge_vec.has_edge(t, s) {
+                    return Err(GraphError::InvalidGraph(format!(
+                        "edge ({},{}) is missing from the in-edges.",
+                        s, t
+                    )));
+                }
+
+                let in_label = in_edge_vec.find_edge_label(t, s);
+                if in_label.is_some() && in_label != self.edge_vec.find_edge_label(s, t) {
+                    return Err(GraphError::InvalidGraph(format!(
+                        "the in-edge of ({},{}) has another label.",
+                        s, t
+                    )));
+                }
+            }
+        } else {
+            for s in self.node_indices() {
+                for &t in self.edge_vec.neighbors(s) {
+                    if !self.edge_vec.has_edge(t, s) {
+                        return Err(GraphError::InvalidGraph(format!(
+                            "edge ({},{}) has no reverse edge ({},{}).",
+                            s, t, t, s
+                        )));
+                    }
+                }
+            }
+        }
+
+        let expected_num_edges = if Ty::is_directed() {
+            self.edge_vec.len()
+        } else {
+            self.edge_vec.len() >> 1
+        };
+
+        if self.num_edges != expected_num_edges {
+            return Err(GraphError::InvalidGraph(format!(
+                "expect {} edges, found {}.",
+                expected_num_edges, self.num_edges
+            )));
+        }
+
+        if let Some(ref labels) = self.labels {
+            if labels.len() != self.num_nodes {
+                return Err(GraphError::InvalidGraph(format!(
+                    "{} labels for {} nodes.",
+                    labels.len(),
+                    self.num_nodes
+                )));
+            }
+
+            check_label_ids("node", labels, self.node_label_map.len())?;
+        }
+
+        let num_edge_labels = self.edge_label_map.len();
+        check_label_ids("edge", self.edge_vec.get_labels(), num_edge_labels)?;
+
+        if let Some(ref in_edge_vec) = self.in_edge_vec {
+            check_label_ids("edge", in_edge_vec.get_labels(), num_edge_labels)?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_edge_vec(&self) -> &EdgeVec<Id> {
         &self.edge_vec
     }
@@ -134,6 +307,14 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> TypedStaticGraph<I
         &self.in_edge_vec
     }
 
+    /// The node label ids, indexed by node, or an empty slice if the nodes are unlabeled.
+    pub fn get_labels(&self) -> &[Id] {
+        match self.labels {
+            Some(ref labels) => &labels[..],
+            None => &[],
+        }
+    }
+
     pub fn shrink_to_fit(&mut self) {
         self.edge_vec.shrink_to_fit();
         if let Some(ref mut in_edge_vec) = self.in_edge_vec {
@@ -144,10 +325,45 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> TypedStaticGraph<I
         }
     }
 
+    /// The index of the edge `(start, target)` in the edge vector. Indices range over
+    /// `0 .. get_edge_vec().len()`, so that per-edge data can be kept in a plain vector.
+    /// Every undirected edge has two indices, one for each direction.
     pub fn find_edge_index(&self, start: Id, target: Id) -> Option<usize> {
         self.edge_vec.find_edge_index(start, target)
     }
 
+    /// The `(start, target)` of the edge at `index`, the inverse of `find_edge_index`.
+    pub fn find_edge(&self, index: usize) -> Option<(Id, Id)> {
+        self.edge_vec.find_edge(index)
+    }
+
+    /// The label of the edge at `index`.
+    pub fn get_edge_label_by_index(&self, index: usize) -> Option<&EL> {
+        match self.edge_vec.get_labels().get(index) {
+            Some(label_id) => self.edge_label_map.get_item(label_id.id()),
+            None => None,
+        }
+    }
+
+    /// The neighbors of `id`, or an error if `id` is out of range.
+    pub fn try_neighbors(&self, id: Id) -> Result<&[Id]> {
+        self.edge_vec.try_neighbors(id)
+    }
+
+    /// The degree of `id`, or an error if `id` is out of range.
+    pub fn try_degree(&self, id: Id) -> Result<usize> {
+        self.edge_vec.try_num_of_neighbors(id)
+    }
+
+    /// The in-neighbors of `id` in a directed graph, or its neighbors in an undirected graph,
+    /// or an error if `id` is out of range.
+    pub fn try_in_neighbors(&self, id: Id) -> Result<&[Id]> {
+        match self.in_edge_vec {
+            Some(ref in_edge_vec) => in_edge_vec.try_neighbors(id),
+            None => self.edge_vec.try_neighbors(id),
+        }
+    }
+
     pub fn to_int_label(mut self) -> TypedStaticGraph<Id, Id, Id, Ty> {
         TypedStaticGraph {
             num_nodes: self.num_nodes,
@@ -162,6 +378,56 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> TypedStaticGraph<I
     }
 }
 
+// Check that every label id is below `num_labels`, or the `Id::max_value()` of no label.
+fn check_label_ids<Id: IdType>(kind: &str, labels: &[Id], num_labels: usize) -> Result<()> {
+    match labels
+        .iter()
+        .find(|&&l| l != Id::max_value() && l.id() >= num_labels)
+    {
+        Some(l) => Err(GraphError::InvalidGraph(format!(
+            "{} label id {} out of {} labels.",
+            kind,
+            l.id(),
+            num_labels
+        ))),
+        None => Ok(()),
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> TypedUnStaticGraph<Id, NL, EL> {
+    /// The index of the edge at `index` in the other direction, e.g., of `(1, 0)` for `(0, 1)`.
+    pub fn find_reverse_edge_index(&self, index: usize) -> Option<usize> {
+        let (start, target) = self.find_edge(index)?;
+
+        self.find_edge_index(target, start)
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> TypedDiStaticGraph<Id, NL, EL> {
+    /// Same as `in_neighbors_with_labels`, without boxing the iterator. The edges are not
+    /// looked up, and the label ids are `None` if the in-edges are unlabeled, as in graphs
+    /// built from an `EdgeVec` without labels.
+    pub fn in_neighbors_with_labels_iter(&self, id: Id) -> LabeledNeighbors<'_, Id> {
+        self.in_edge_vec.as_ref().unwrap().neighbors_with_labels(id)
+    }
+
+    /// The index of the in-edge `(target, start)` in the in-edge vector, for the edge
+    /// `(start, target)` at `index` of the edge vector.
+    pub fn find_in_edge_index(&self, index: usize) -> Option<usize> {
+        let (start, target) = self.find_edge(index)?;
+
+        self.in_edge_vec.as_ref()?.find_edge_index(target, start)
+    }
+
+    /// The inverse of `find_in_edge_index`: the index in the edge vector of the edge whose
+    /// in-edge is at `in_index` of the in-edge vector.
+    pub fn find_out_edge_index(&self, in_index: usize) -> Option<usize> {
+        let (target, start) = self.in_edge_vec.as_ref()?.find_edge(in_index)?;
+
+        self.find_edge_index(start, target)
+    }
+}
+
 impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> GraphTrait<Id>
     for TypedStaticGraph<Id, NL, EL, Ty>
 {
@@ -185,8 +451,12 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> GraphTrait<Id>
             return EdgeType::None;
         }
 
-        let label = self.edge_vec.find_edge_label(start, target);
-        EdgeType::StaticEdge(Edge::new(start, target, label.cloned()))
+        let edge = match self.edge_vec.find_edge_label(start, target) {
+            Some(&label) => Edge::new_static(start, target, label),
+            None => Edge::new(start, target, None),
+        };
+
+        EdgeType::StaticEdge(edge)
     }
 
     fn has_node(&self, id: Id) -> bool {
@@ -270,6 +540,13 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> GraphTrait<Id>
     fn num_of_neighbors(&self, node: Id) -> usize {
         self.edge_vec.num_of_neighbors(node)
     }
+
+    fn neighbors_with_labels<'a>(&'a self, id: Id) -> Iter<'a, (Id, Option<Id>)>
+    where
+        Id: 'a,
+    {
+        Iter::new(Box::new(self.edge_vec.neighbors_with_labels(id)))
+    }
     //
     //    fn get_node_label_id(&self, node_id: Id) -> Option<Id> {
     //        match self.labels {
@@ -333,6 +610,43 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> DiGraphTrait<Id> for TypedDiStati
     fn num_of_in_neighbors(&self, node: Id) -> usize {
         self.in_edge_vec.as_ref().unwrap().num_of_neighbors(node)
     }
+
+    fn in_neighbors_with_labels<'a>(&'a self, id: Id) -> Iter<'a, (Id, Option<Id>)>
+    where
+        Id: 'a,
+    {
+        Iter::new(Box::new(self.in_neighbors_with_labels_iter(id)))
+    }
+}
+
+impl<'a, Id: 'a + IdType, NL: 'a + Hash + Eq, EL: 'a + Hash + Eq, Ty: 'a + GraphType>
+    GraphIterTrait<'a, Id> for TypedStaticGraph<Id, NL, EL, Ty>
+{
+    type NodeIndices = Map<Range<usize>, fn(usize) -> Id>;
+    type EdgeIndices = EdgeIter<'a, Id, NL, EL, Ty>;
+    type Neighbors = Cloned<slice::Iter<'a, Id>>;
+
+    fn iter_node_indices(&'a self) -> Self::NodeIndices {
+        (0..self.num_nodes).map(Id::new as fn(usize) -> Id)
+    }
+
+    fn iter_edge_indices(&'a self) -> Self::EdgeIndices {
+        EdgeIter::new(self)
+    }
+
+    fn iter_neighbors(&'a self, id: Id) -> Self::Neighbors {
+        self.edge_vec.neighbors(id).iter().cloned()
+    }
+}
+
+impl<'a, Id: 'a + IdType, NL: 'a + Hash + Eq, EL: 'a + Hash + Eq> DiGraphIterTrait<'a, Id>
+    for TypedDiStaticGraph<Id, NL, EL>
+{
+    type InNeighbors = Cloned<slice::Iter<'a, Id>>;
+
+    fn iter_in_neighbors(&'a self, id: Id) -> Self::InNeighbors {
+        self.in_edge_vec.as_ref().unwrap().neighbors(id).iter().cloned()
+    }
 }
 
 pub struct EdgeIter<'a, Id: 'a + IdType, NL: 'a + Hash + Eq, EL: 'a + Hash + Eq, Ty: 'a + GraphType>
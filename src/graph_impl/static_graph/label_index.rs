@@ -0,0 +1,177 @@
+use std::cmp::min;
+use std::hash::Hash;
+
+use generic::{GraphLabelTrait, GraphType, IdType, MapTrait};
+use graph_impl::static_graph::{EdgeVec, TypedStaticGraph};
+
+/// An index of the neighbors of every node, grouped by their labels, so that the neighbors of
+/// a given label are found in O(log d) as a slice, sorted by id, where `d` is the degree.
+///
+/// The neighbors of node `v` are partitioned into `num_node_labels + 1` groups, the last of
+/// which holds the neighbors of no label (or an unknown label id). If the index is built by
+/// edge labels too, every group is further partitioned by edge labels in the same way.
+///
+/// # Space
+///
+/// Only the non-empty groups are stored, with a key and an offset each, so the index takes
+/// `|V| + 2 * G + 2` `usize`s on top of a copy of the neighbors, where `G <= |E|` is the
+/// number of non-empty groups, regardless of the number of labels.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct LabelIndex<Id: IdType> {
+    num_nodes: usize,
+    by_edge_label: bool,
+    // The number of groups by node labels and by edge labels, respectively.
+    num_node_keys: usize,
+    num_edge_keys: usize,
+    // The non-empty groups of `v` are `groups[v] .. groups[v + 1]`. Group `i` has the key
+    // `node_key * num_edge_keys + edge_key` in `keys[i]`, sorted for every node, and its
+    // neighbors start at `starts[i]` and end where the next group starts.
+    groups: Vec<usize>,
+    keys: Vec<usize>,
+    starts: Vec<usize>,
+    neighbors: Vec<Id>,
+}
+
+impl<Id: IdType> LabelIndex<Id> {
+    /// Index the neighbors of every node in `g` by their labels, and by the labels of the
+    /// edges to them if `by_edge_label`.
+    pub fn new<NL, EL, Ty>(g: &TypedStaticGraph<Id, NL, EL, Ty>, by_edge_label: bool) -> Self
+    where
+        NL: Hash + Eq,
+        EL: Hash + Eq,
+        Ty: GraphType,
+    {
+        let num_edge_labels = if by_edge_label {
+            Some(g.get_edge_label_map().len())
+        } else {
+            None
+        };
+
+        Self::from_edge_vec(
+            g.get_edge_vec(),
+            g.get_labels(),
+            g.get_node_label_map().len(),
+            num_edge_labels,
+        )
+    }
+
+    /// Index the neighbors in `edge_vec`, e.g., the in-edges of a directed graph, by their
+    /// labels in `node_labels`, which range over `0 .. num_node_labels`, and by the edge
+    /// labels, which range over `0 .. num_edge_labels`, if given.
+    pub fn from_edge_vec(
+        edge_vec: &EdgeVec<Id>,
+        node_labels: &[Id],
+        num_node_labels: usize,
+        num_edge_labels: Option<usize>,
+    ) -> Self {
+        let num_nodes = edge_vec.num_nodes();
+        let num_node_keys = num_node_labels + 1;
+        let num_edge_keys = num_edge_labels.map_or(1, |n| n + 1);
+
+        let mut groups = Vec::with_capacity(num_nodes + 1);
+        let mut keys = Vec::new();
+        let mut starts = Vec::new();
+        let mut neighbors = Vec::with_capacity(edge_vec.len());
+
+        for v in 0..num_nodes {
+            let mut keyed: Vec<(usize, Id)> = edge_vec
+                .neighbors_with_labels(Id::new(v))
+                .map(|(n, edge_label)| {
+                    let node_key = node_labels
+                        .get(n.id())
+                        .map_or(num_node_labels, |l| min(l.id(), num_node_labels));
+                    let edge_key = match (num_edge_labels, edge_label) {
+                        (None, _) => 0,
+                        (Some(num), Some(l)) => min(l.id(), num),
+                        (Some(num), None) => num,
+                    };
+
+                    (node_key * num_edge_keys + edge_key, n)
+                })
+                .collect();
+
+            // The neighbors are sorted, and remain sorted in every group as the sort is stable.
+            keyed.sort_by_key(|&(key, _)| key);
+
+            groups.push(keys.len());
+
+            for (key, n) in keyed {
+                if keys.len() == groups[v] || keys.last() != Some(&key) {
+                    keys.push(key);
+                    starts.push(neighbors.len());
+                }
+
+                neighbors.push(n);
+            }
+        }
+
+        groups.push(keys.len());
+        starts.push(neighbors.len());
+
+        LabelIndex {
+            num_nodes,
+            by_edge_label: num_edge_labels.is_some(),
+            num_node_keys,
+            num_edge_keys,
+            groups,
+            keys,
+            starts,
+            neighbors,
+        }
+    }
+
+    /// Whether the neighbors are grouped by edge labels too.
+    pub fn is_by_edge_label(&self) -> bool {
+        self.by_edge_label
+    }
+
+    /// The neighbors of `id` with node label id `label`, sorted by id. An unknown label id,
+    /// e.g., `Id::max_value()`, gives the neighbors of no label.
+    pub fn neighbors_by_label(&self, id: Id, label: Id) -> &[Id] {
+        assert!(id.id() < self.num_nodes);
+        let (first, keys) = self.groups_of(id);
+
+        // The groups of the edge labels of a node label are consecutive.
+        let key = self.node_key(label) * self.num_edge_keys;
+        let start = first + lower_bound(keys, key);
+        let end = first + lower_bound(keys, key + self.num_edge_keys);
+
+        &self.neighbors[self.starts[start]..self.starts[end]]
+    }
+
+    /// The neighbors of `id` with node label id `label`, along the edges of label id
+    /// `edge_label`, sorted by id. The index must be built by edge labels.
+    pub fn neighbors_by_labels(&self, id: Id, label: Id, edge_label: Id) -> &[Id] {
+        assert!(id.id() < self.num_nodes);
+        assert!(
+            self.is_by_edge_label(),
+            "The index is not built by edge labels."
+        );
+        let (first, keys) = self.groups_of(id);
+        let key = self.node_key(label) * self.num_edge_keys
+            + min(edge_label.id(), self.num_edge_keys - 1);
+
+        match keys.binary_search(&key) {
+            Ok(i) => &self.neighbors[self.starts[first + i]..self.starts[first + i + 1]],
+            Err(_) => &[],
+        }
+    }
+
+    fn node_key(&self, label: Id) -> usize {
+        min(label.id(), self.num_node_keys - 1)
+    }
+
+    // The first group of `id`, and the keys of its groups.
+    fn groups_of(&self, id: Id) -> (usize, &[usize]) {
+        let first = self.groups[id.id()];
+
+        (first, &self.keys[first..self.groups[id.id() + 1]])
+    }
+}
+
+// The index of the first key in `keys` that is not less than `key`.
+fn lower_bound(keys: &[usize], key: usize) -> usize {
+    match keys.binary_search(&key) {
+        Ok(i) | Err(i) => i,
+    }
+}
@@ -0,0 +1,46 @@
+use generic::IdType;
+use generic::{EdgeTrait, MutEdgeTrait};
+
+/// An edge that appeared at `timestamp`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct TimedEdge<Id: IdType> {
+    start: Id,
+    target: Id,
+    timestamp: u64,
+    label: Option<Id>,
+}
+
+impl<Id: IdType> TimedEdge<Id> {
+    pub fn new(start: Id, target: Id, timestamp: u64, label: Option<Id>) -> Self {
+        TimedEdge {
+            start,
+            target,
+            timestamp,
+            label,
+        }
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+impl<Id: IdType> EdgeTrait<Id> for TimedEdge<Id> {
+    fn get_start(&self) -> Id {
+        self.start
+    }
+
+    fn get_target(&self) -> Id {
+        self.target
+    }
+
+    fn get_label_id(&self) -> Option<Id> {
+        self.label
+    }
+}
+
+impl<Id: IdType> MutEdgeTrait<Id> for TimedEdge<Id> {
+    fn set_label_id(&mut self, label: Option<Id>) {
+        self.label = label
+    }
+}
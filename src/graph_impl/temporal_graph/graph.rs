@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use generic::GraphType;
+use generic::Iter;
+use generic::{DefaultId, IdType};
+use generic::{DefaultTy, Directed, Undirected};
+use generic::{EdgeTrait, MapTrait, MutMapTrait};
+use generic::{GraphTrait, MutGraphTrait};
+
+use graph_impl::temporal_graph::TimedEdge;
+use graph_impl::TypedGraphMap;
+
+use map::SetMap;
+
+pub type TypedDiTemporalGraph<Id, NL, EL = NL> = TypedTemporalGraph<Id, NL, EL, Directed>;
+pub type TypedUnTemporalGraph<Id, NL, EL = NL> = TypedTemporalGraph<Id, NL, EL, Undirected>;
+pub type TemporalGraph<NL, EL, Ty = DefaultTy> = TypedTemporalGraph<DefaultId, NL, EL, Ty>;
+pub type DiTemporalGraph<NL, EL = NL> = TemporalGraph<NL, EL, Directed>;
+pub type UnTemporalGraph<NL, EL = NL> = TemporalGraph<NL, EL, Undirected>;
+
+/// A graph whose edges are stored in order of their timestamps. The same edge may appear
+/// more than once, at different times.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypedTemporalGraph<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> {
+    /// A map <node_id:label_id>.
+    nodes: BTreeMap<Id, Option<Id>>,
+    /// The edges, sorted by timestamp, and by insertion for equal timestamps.
+    edges: Vec<TimedEdge<Id>>,
+    /// A map of node labels.
+    node_label_map: SetMap<NL>,
+    /// A map of edge labels.
+    edge_label_map: SetMap<EL>,
+    /// A marker of the graph type, namely, directed or undirected.
+    graph_type: PhantomData<Ty>,
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> TypedTemporalGraph<Id, NL, EL, Ty> {
+    /// Constructs a new temporal graph.
+    pub fn new() -> Self {
+        Self::with_label_map(SetMap::new(), SetMap::new())
+    }
+
+    /// Constructs a new temporal graph using existing label-id mapping.
+    pub fn with_label_map(node_label_map: SetMap<NL>, edge_label_map: SetMap<EL>) -> Self {
+        TypedTemporalGraph {
+            nodes: BTreeMap::new(),
+            edges: Vec::new(),
+            node_label_map,
+            edge_label_map,
+            graph_type: PhantomData,
+        }
+    }
+
+    /// Add a node with `id` and `label`. If the node of the `id` already presents,
+    /// replace the node's label with the new `label` and return `false`.
+    /// Otherwise, add the node and return `true`.
+    pub fn add_node(&mut self, id: Id, label: Option<NL>) -> bool {
+        let label_id = label.map(|x| Id::new(self.node_label_map.add_item(x)));
+
+        self.nodes.insert(id, label_id).is_none()
+    }
+
+    /// Add the edge from `start` to `target` at `timestamp`, after the edges of earlier or
+    /// equal timestamps. If either end does not exist, add a new node with corresponding id
+    /// and `None` label.
+    pub fn add_edge(&mut self, start: Id, target: Id, timestamp: u64, label: Option<EL>) {
+        let label_id = label.map(|x| Id::new(self.edge_label_map.add_item(x)));
+
+        self.nodes.entry(start).or_insert(None);
+        self.nodes.entry(target).or_insert(None);
+
+        // Edges usually arrive in order, in which case this is a push.
+        let index = self
+            .edges
+            .partition_point(|edge| edge.get_timestamp() <= timestamp);
+        self.edges
+            .insert(index, TimedEdge::new(start, target, timestamp, label_id));
+    }
+
+    pub fn has_node(&self, id: Id) -> bool {
+        self.nodes.contains_key(&id)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The number of timed edges, counting every appearance of the same edge.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// The node ids in ascending order.
+    pub fn node_indices<'a>(&'a self) -> Iter<'a, Id> {
+        Iter::new(Box::new(self.nodes.keys().cloned()))
+    }
+
+    /// All edges, in order of time.
+    pub fn edges(&self) -> &[TimedEdge<Id>] {
+        &self.edges
+    }
+
+    /// The edges whose timestamps are in `[start, end)`, in order of time.
+    pub fn edges_in_window(&self, start: u64, end: u64) -> &[TimedEdge<Id>] {
+        let lower = self
+            .edges
+            .partition_point(|edge| edge.get_timestamp() < start);
+        let upper = self
+            .edges
+            .partition_point(|edge| edge.get_timestamp() < end)
+            .max(lower);
+
+        &self.edges[lower..upper]
+    }
+
+    /// The earliest and latest timestamps, or `None` if there is no edge.
+    pub fn time_span(&self) -> Option<(u64, u64)> {
+        match (self.edges.first(), self.edges.last()) {
+            (Some(first), Some(last)) => Some((first.get_timestamp(), last.get_timestamp())),
+            _ => None,
+        }
+    }
+
+    pub fn get_node_label(&self, id: Id) -> Option<&NL> {
+        match self.nodes.get(&id) {
+            Some(&Some(label_id)) => self.node_label_map.get_item(label_id.id()),
+            _ => None,
+        }
+    }
+
+    pub fn get_edge_label(&self, edge: &TimedEdge<Id>) -> Option<&EL> {
+        match edge.get_label_id() {
+            Some(label_id) => self.edge_label_map.get_item(label_id.id()),
+            None => None,
+        }
+    }
+
+    pub fn get_node_label_map(&self) -> &SetMap<NL> {
+        &self.node_label_map
+    }
+
+    pub fn get_edge_label_map(&self) -> &SetMap<EL> {
+        &self.edge_label_map
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq + Clone, EL: Hash + Eq + Clone, Ty: GraphType>
+    TypedTemporalGraph<Id, NL, EL, Ty>
+{
+    /// The graph of the edges whose timestamps are in `[start, end)`, together with their
+    /// ends. An edge appearing more than once in the window keeps its latest label. The
+    /// snapshot shares the label-id mapping of this graph, so label ids agree across
+    /// snapshots.
+    pub fn snapshot(&self, start: u64, end: u64) -> TypedGraphMap<Id, NL, EL, Ty> {
+        self.graph_of(self.edges_in_window(start, end))
+    }
+
+    /// The graph of all edges, with every node, including isolated ones.
+    pub fn to_graph_map(&self) -> TypedGraphMap<Id, NL, EL, Ty> {
+        let mut g = self.graph_of(&self.edges);
+
+        for id in self.node_indices() {
+            if !g.has_node(id) {
+                g.add_node(id, self.get_node_label(id).cloned());
+            }
+        }
+
+        g
+    }
+
+    fn graph_of(&self, edges: &[TimedEdge<Id>]) -> TypedGraphMap<Id, NL, EL, Ty> {
+        let mut g =
+            TypedGraphMap::with_label_map(self.node_label_map.clone(), self.edge_label_map.clone());
+
+        for edge in edges {
+            for &id in &[edge.get_start(), edge.get_target()] {
+                if !g.has_node(id) {
+                    g.add_node(id, self.get_node_label(id).cloned());
+                }
+            }
+
+            g.add_edge(
+                edge.get_start(),
+                edge.get_target(),
+                self.get_edge_label(edge).cloned(),
+            );
+        }
+
+        g
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> Default
+    for TypedTemporalGraph<Id, NL, EL, Ty>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,27 @@
+//! A graph whose edges carry timestamps, e.g., an edge stream for streaming subgraph
+//! matching. The edges are kept in order of time, so the graph of any time window can be
+//! extracted as a `TypedGraphMap`.
+//!
+//! # Example
+//! ```
+//! use rust_graph::graph_impl::UnTemporalGraph;
+//! use rust_graph::prelude::*;
+//!
+//! let mut g = UnTemporalGraph::<&str>::new();
+//! g.add_edge(0, 1, 10, Some("a"));
+//! g.add_edge(1, 2, 20, None);
+//! g.add_edge(2, 0, 30, Some("b"));
+//!
+//! let snapshot = g.snapshot(10, 30);
+//! assert_eq!(snapshot.edge_count(), 2);
+//! assert!(!snapshot.has_edge(2, 0));
+//! ```
+
+pub mod edge;
+pub mod graph;
+
+pub use graph_impl::temporal_graph::edge::TimedEdge;
+pub use graph_impl::temporal_graph::graph::{DiTemporalGraph, TemporalGraph, UnTemporalGraph};
+pub use graph_impl::temporal_graph::graph::{
+    TypedDiTemporalGraph, TypedTemporalGraph, TypedUnTemporalGraph,
+};
@@ -8,8 +8,9 @@ use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
+use error::{self, GraphError};
 use generic::IdType;
-use generic::{GeneralGraph, MutGraphTrait};
+use generic::{GeneralGraph, GraphTrait, MutGraphTrait};
 use io::csv::reader::GraphReader;
 use io::csv::writer::GraphWriter;
 
@@ -36,7 +37,7 @@ where
     for<'de> Id: IdType + Serialize + Deserialize<'de>,
     for<'de> NL: Hash + Eq + Serialize + Deserialize<'de>,
     for<'de> EL: Hash + Eq + Serialize + Deserialize<'de>,
-    G: MutGraphTrait<Id, NL, EL>,
+    G: MutGraphTrait<Id, NL, EL> + GraphTrait<Id>,
     P: AsRef<Path>,
 {
     GraphReader::new(path_to_nodes, path_to_edges).read(g)
@@ -44,3 +45,22 @@ where
 
 //impl<Ty: GraphType, NL: Hash + Eq, EL: Hash + Eq> GraphReader<Ty, NL, EL> {
 //    pub fn new<P: AsRef<Path>>(path_to_nodes: P, path_to_edges: P) -> Self {
+
+// Parse a separator given either as a single character or by its name.
+fn parse_separator(separator: &str) -> error::Result<u8> {
+    let sep_string = match separator {
+        "comma" => ",",
+        "space" => " ",
+        "tab" => "\t",
+        other => other,
+    };
+
+    if sep_string.len() != 1 {
+        return Err(GraphError::InvalidArgument(format!(
+            "Invalid separator {}.",
+            sep_string
+        )));
+    }
+
+    Ok(sep_string.as_bytes()[0])
+}
@@ -8,17 +8,20 @@ use std::io::Result;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
 
-use csv::ReaderBuilder;
+use csv::{self, ReaderBuilder};
 use serde::Deserialize;
 
+use error;
 use generic::IdType;
-use generic::MutGraphTrait;
+use generic::{DuplicatePolicy, GraphTrait, MutGraphTrait};
+use io::csv::parse_separator;
 use io::csv::record::{EdgeRecord, NodeRecord};
 
 pub struct GraphReader<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> {
     path_to_nodes: Option<PathBuf>,
     path_to_edges: PathBuf,
     separator: u8,
+    duplicate_policy: DuplicatePolicy,
     id_type: PhantomData<Id>,
     nl_type: PhantomData<NL>,
     el_type: PhantomData<EL>,
@@ -30,6 +33,7 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> GraphReader<Id, NL, EL> {
             path_to_nodes: path_to_nodes.map_or(None, |x| Some(x.as_ref().to_path_buf())),
             path_to_edges: path_to_edges.as_ref().to_path_buf(),
             separator: b',',
+            duplicate_policy: DuplicatePolicy::Overwrite,
             id_type: PhantomData,
             nl_type: PhantomData,
             el_type: PhantomData,
@@ -41,25 +45,33 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> GraphReader<Id, NL, EL> {
         path_to_edges: P,
         separator: &str,
     ) -> Self {
-        let sep_string = match separator {
-            "comma" => ",",
-            "space" => " ",
-            "tab" => "\t",
-            other => other,
-        };
-
-        if sep_string.len() != 1 {
-            panic!("Invalid separator {}.", sep_string);
-        }
+        Self::try_with_separator(path_to_nodes, path_to_edges, separator)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        GraphReader {
+    /// Same as `with_separator`, but return an error if the separator is invalid.
+    pub fn try_with_separator<P: AsRef<Path>>(
+        path_to_nodes: Option<P>,
+        path_to_edges: P,
+        separator: &str,
+    ) -> error::Result<Self> {
+        Ok(GraphReader {
             path_to_nodes: path_to_nodes.map_or(None, |x| Some(x.as_ref().to_path_buf())),
             path_to_edges: path_to_edges.as_ref().to_path_buf(),
-            separator: sep_string.chars().next().unwrap() as u8,
+            separator: parse_separator(separator)?,
+            duplicate_policy: DuplicatePolicy::Overwrite,
             id_type: PhantomData,
             nl_type: PhantomData,
             el_type: PhantomData,
-        }
+        })
+    }
+
+    /// How the nodes and edges already in the graph, or repeated in the files, are
+    /// treated. By default, their labels are overwritten.
+    pub fn with_duplicate_policy(mut self, duplicate_policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = duplicate_policy;
+
+        self
     }
 }
 
@@ -69,7 +81,7 @@ where
     for<'de> NL: Deserialize<'de>,
     for<'de> EL: Deserialize<'de>,
 {
-    pub fn read<G: MutGraphTrait<Id, NL, EL>>(&self, g: &mut G) -> Result<()> {
+    pub fn read<G: MutGraphTrait<Id, NL, EL> + GraphTrait<Id>>(&self, g: &mut G) -> Result<()> {
         if let Some(ref path_to_nodes) = self.path_to_nodes {
             info!(
                 "csv::Reader::read - Adding nodes from {}",
@@ -79,10 +91,19 @@ where
                 .delimiter(self.separator)
                 .from_path(path_to_nodes.as_path())?;
 
-            for result in rdr.deserialize() {
-                let record: NodeRecord<Id, NL> = result?;
-                record.add_to_graph(g);
+            let mut error = None;
+            let nodes = until_error(rdr.deserialize(), &mut error).map(NodeRecord::into_node);
+            let stats = g.extend_nodes(nodes, self.duplicate_policy)?;
+
+            if let Some(e) = error {
+                return Err(e.into());
             }
+
+            info!(
+                "csv::Reader::read - {} nodes added, {} duplicates.",
+                stats.inserted,
+                stats.duplicates()
+            );
         }
 
         info!(
@@ -94,11 +115,39 @@ where
             .delimiter(self.separator)
             .from_path(self.path_to_edges.as_path())?;
 
-        for result in rdr.deserialize() {
-            let record: EdgeRecord<Id, EL> = result?;
-            record.add_to_graph(g);
+        let mut error = None;
+        let edges = until_error(rdr.deserialize(), &mut error).map(EdgeRecord::into_edge);
+        let stats = g.extend_edges(edges, self.duplicate_policy)?;
+
+        if let Some(e) = error {
+            return Err(e.into());
         }
 
+        info!(
+            "csv::Reader::read - {} edges added, {} duplicates.",
+            stats.inserted,
+            stats.duplicates()
+        );
+
         Ok(())
     }
 }
+
+// The records up to the first error, which is kept in `error`, so that they are inserted as
+// they are read.
+fn until_error<'a, T, I>(
+    records: I,
+    error: &'a mut Option<csv::Error>,
+) -> impl Iterator<Item = T> + 'a
+where
+    T: 'a,
+    I: Iterator<Item = csv::Result<T>> + 'a,
+{
+    records.scan(error, |error, result| match result {
+        Ok(record) => Some(record),
+        Err(e) => {
+            **error = Some(e);
+            None
+        }
+    })
+}
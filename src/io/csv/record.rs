@@ -29,6 +29,10 @@ impl<Id: IdType, NL: Hash + Eq> NodeRecord<Id, NL> {
     pub fn add_to_graph<EL: Hash + Eq, G: MutGraphTrait<Id, NL, EL>>(self, g: &mut G) {
         g.add_node(self.id, self.label);
     }
+
+    pub fn into_node(self) -> (Id, Option<NL>) {
+        (self.id, self.label)
+    }
 }
 
 impl<Id: IdType, EL: Hash + Eq> EdgeRecord<Id, EL> {
@@ -43,4 +47,8 @@ impl<Id: IdType, EL: Hash + Eq> EdgeRecord<Id, EL> {
     pub fn add_to_graph<NL: Hash + Eq, G: MutGraphTrait<Id, NL, EL>>(self, g: &mut G) {
         g.add_edge(self.start, self.target, self.label);
     }
+
+    pub fn into_edge(self) -> (Id, Id, Option<EL>) {
+        (self.start, self.target, self.label)
+    }
 }
@@ -5,8 +5,10 @@ use std::path::{Path, PathBuf};
 use csv::WriterBuilder;
 use serde::Serialize;
 
+use error;
 use generic::GeneralGraph;
 use generic::IdType;
+use io::csv::parse_separator;
 use io::csv::record::{EdgeRecord, NodeRecord};
 
 pub struct GraphWriter<'a, Id, NL, EL>
@@ -46,23 +48,23 @@ where
         path_to_edges: P,
         separator: &str,
     ) -> Self {
-        let sep_string = match separator {
-            "comma" => ",",
-            "space" => " ",
-            "tab" => "\t",
-            other => other,
-        };
-
-        if sep_string.len() != 1 {
-            panic!("Invalid separator {}.", sep_string);
-        }
+        Self::try_with_separator(g, path_to_nodes, path_to_edges, separator)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        GraphWriter {
+    /// Same as `with_separator`, but return an error if the separator is invalid.
+    pub fn try_with_separator<P: AsRef<Path>>(
+        g: &'a dyn GeneralGraph<Id, NL, EL>,
+        path_to_nodes: P,
+        path_to_edges: P,
+        separator: &str,
+    ) -> error::Result<Self> {
+        Ok(GraphWriter {
             g,
             path_to_nodes: path_to_nodes.as_ref().to_path_buf(),
             path_to_edges: path_to_edges.as_ref().to_path_buf(),
-            separator: sep_string.chars().next().unwrap() as u8,
-        }
+            separator: parse_separator(separator)?,
+        })
     }
 }
 
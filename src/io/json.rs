@@ -0,0 +1,251 @@
+//! Reading and writing graphs in JSON, in the formats of `node_link_data` and
+//! `adjacency_data` of networkx, which are also used by d3.
+//!
+//! Node-link format:
+//!
+//! ```text
+//! {"directed": false, "multigraph": false, "graph": {},
+//!  "nodes": [{"id": 0, "label": "a"}, {"id": 1}],
+//!  "links": [{"source": 0, "target": 1, "label": "x"}]}
+//! ```
+//!
+//! Adjacency format, where `adjacency[i]` lists the neighbours of `nodes[i]`
+//! (in both directions for undirected graphs):
+//!
+//! ```text
+//! {"directed": false, "multigraph": false, "graph": {},
+//!  "nodes": [{"id": 0, "label": "a"}, {"id": 1}],
+//!  "adjacency": [[{"id": 1, "label": "x"}], [{"id": 0, "label": "x"}]]}
+//! ```
+//!
+//! Labels are kept in the `label` attribute, and other attributes are ignored.
+
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufReader, BufWriter, Error, ErrorKind, Result};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json;
+use serde_json::{Map, Value};
+
+use generic::IdType;
+use generic::{GeneralGraph, GraphTrait, MutGraphTrait};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFormat {
+    NodeLink,
+    Adjacency,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonNode<Id, NL> {
+    id: Id,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<NL>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonLink<Id, EL> {
+    source: Id,
+    target: Id,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<EL>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonAdjacency<Id, EL> {
+    id: Id,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<EL>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NodeLinkGraph<Id, NL, EL> {
+    directed: bool,
+    #[serde(default)]
+    multigraph: bool,
+    #[serde(default)]
+    graph: Map<String, Value>,
+    nodes: Vec<JsonNode<Id, NL>>,
+    links: Vec<JsonLink<Id, EL>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AdjacencyGraph<Id, NL, EL> {
+    directed: bool,
+    #[serde(default)]
+    multigraph: bool,
+    #[serde(default)]
+    graph: Map<String, Value>,
+    nodes: Vec<JsonNode<Id, NL>>,
+    adjacency: Vec<Vec<JsonAdjacency<Id, EL>>>,
+}
+
+pub fn write_to_json<Id, NL, EL, G, P>(g: &G, path: P, format: JsonFormat) -> Result<()>
+where
+    Id: IdType + Serialize,
+    NL: Hash + Eq + Serialize,
+    EL: Hash + Eq + Serialize,
+    G: ?Sized + GeneralGraph<Id, NL, EL>,
+    P: AsRef<Path>,
+{
+    info!(
+        "json::write_to_json - Writing graph to {}",
+        path.as_ref().to_str().unwrap()
+    );
+
+    let writer = BufWriter::new(File::create(path)?);
+    let labeled = g.as_labeled_graph();
+
+    let nodes: Vec<_> = g
+        .node_indices()
+        .map(|id| JsonNode {
+            id,
+            label: labeled.get_node_label(id),
+        })
+        .collect();
+
+    match format {
+        JsonFormat::NodeLink => {
+            let links = g
+                .edge_indices()
+                .map(|(source, target)| JsonLink {
+                    source,
+                    target,
+                    label: labeled.get_edge_label(source, target),
+                })
+                .collect();
+
+            let graph = NodeLinkGraph {
+                directed: g.is_directed(),
+                multigraph: false,
+                graph: Map::new(),
+                nodes,
+                links,
+            };
+
+            serde_json::to_writer(writer, &graph)?;
+        }
+        JsonFormat::Adjacency => {
+            let adjacency = nodes
+                .iter()
+                .map(|node| {
+                    g.neighbors_iter(node.id)
+                        .map(|id| JsonAdjacency {
+                            id,
+                            label: labeled.get_edge_label(node.id, id),
+                        })
+                        .collect()
+                })
+                .collect();
+
+            let graph = AdjacencyGraph {
+                directed: g.is_directed(),
+                multigraph: false,
+                graph: Map::new(),
+                nodes,
+                adjacency,
+            };
+
+            serde_json::to_writer(writer, &graph)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the nodes and edges in a JSON file into `g`. For undirected graphs in adjacency
+/// format, an edge may be listed at either or both of its ends, and is added only once.
+/// The file must agree with `g` on whether the graph is directed.
+pub fn read_from_json<Id, NL, EL, G, P>(g: &mut G, path: P, format: JsonFormat) -> Result<()>
+where
+    for<'de> Id: IdType + Deserialize<'de>,
+    for<'de> NL: Hash + Eq + Deserialize<'de>,
+    for<'de> EL: Hash + Eq + Deserialize<'de>,
+    G: MutGraphTrait<Id, NL, EL> + GraphTrait<Id>,
+    P: AsRef<Path>,
+{
+    info!(
+        "json::read_from_json - Reading graph from {}",
+        path.as_ref().to_str().unwrap()
+    );
+
+    let reader = BufReader::new(File::open(path)?);
+
+    match format {
+        JsonFormat::NodeLink => {
+            let graph: NodeLinkGraph<Id, NL, EL> = serde_json::from_reader(reader)?;
+
+            check_directed(graph.directed, g.is_directed())?;
+            check_multigraph(graph.multigraph)?;
+
+            for node in graph.nodes {
+                g.add_node(node.id, node.label);
+            }
+
+            for link in graph.links {
+                g.add_edge(link.source, link.target, link.label);
+            }
+        }
+        JsonFormat::Adjacency => {
+            let graph: AdjacencyGraph<Id, NL, EL> = serde_json::from_reader(reader)?;
+
+            check_directed(graph.directed, g.is_directed())?;
+            check_multigraph(graph.multigraph)?;
+
+            if graph.nodes.len() != graph.adjacency.len() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Found {} nodes but {} adjacency lists.",
+                        graph.nodes.len(),
+                        graph.adjacency.len()
+                    ),
+                ));
+            }
+
+            let ids: Vec<_> = graph.nodes.iter().map(|node| node.id).collect();
+
+            for node in graph.nodes {
+                g.add_node(node.id, node.label);
+            }
+
+            for (source, neighbors) in ids.into_iter().zip(graph.adjacency) {
+                for neighbor in neighbors {
+                    if graph.directed || !g.has_edge(source, neighbor.id) {
+                        g.add_edge(source, neighbor.id, neighbor.label);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_directed(directed: bool, expected: bool) -> Result<()> {
+    if directed != expected {
+        Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Reading a{} graph into a{} graph.",
+                if directed { " directed" } else { "n undirected" },
+                if expected { " directed" } else { "n undirected" }
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_multigraph(multigraph: bool) -> Result<()> {
+    if multigraph {
+        Err(Error::new(
+            ErrorKind::InvalidData,
+            "Multigraphs are not supported.",
+        ))
+    } else {
+        Ok(())
+    }
+}
@@ -1,15 +1,22 @@
 pub mod node;
 pub mod relation;
+pub mod report;
 pub mod scheme;
 
-pub use io::ldbc::scheme::Scheme;
+pub use io::ldbc::report::{FileReport, LoadReport};
+pub use io::ldbc::scheme::{LdbcGraph, Scheme};
 
 use generic::{GraphType, IdType};
 use graph_impl::TypedGraphMap;
+use std::collections::HashMap;
+use std::io::Result;
 use std::path::Path;
 
+/// Properties of nodes, as a map of `<node_id:<column:value>>`.
+pub type NodeProperties<Id> = HashMap<Id, HashMap<String, String>>;
+
 pub fn read_ldbc_from_path<Id: IdType, Ty: GraphType, P: AsRef<Path>>(
     path: P,
-) -> TypedGraphMap<Id, String, String, Ty> {
-    self::scheme::Scheme::init().from_path(path).unwrap()
+) -> Result<TypedGraphMap<Id, String, String, Ty>> {
+    self::scheme::Scheme::init().from_path(path)
 }
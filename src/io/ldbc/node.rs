@@ -1,54 +1,106 @@
 use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Result};
 use std::path::PathBuf;
 
 use csv::StringRecord;
+use regex;
 use regex::Regex;
 
-use generic::{GraphTrait, MutGraphTrait};
-use generic::{GraphType, IdType};
-use graph_impl::graph_map::TypedGraphMap;
+use generic::IdType;
+use io::ldbc::NodeProperties;
 
 #[derive(Debug)]
 pub struct Node {
     name: String,
     id_index: usize,
-    label_index: usize,
+    // The column of the node label, or `None` to label every node with `name`.
+    label_index: Option<usize>,
+    // The names of the columns to be kept as node properties.
+    properties: Vec<String>,
     file_name_start: Regex,
 }
 
 impl Node {
-    pub fn new(name: &str, id_index: usize, label_index: usize, file_name_start: &str) -> Self {
+    pub fn new(
+        name: &str,
+        id_index: usize,
+        label_index: Option<usize>,
+        file_name_start: &str,
+    ) -> Self {
         Node {
             name: name.to_owned(),
             id_index,
             label_index,
-            file_name_start: Regex::new(&format!(r"^{}[_\d]*.csv", file_name_start)[..]).unwrap(),
+            properties: Vec::new(),
+            file_name_start: Regex::new(&format!(
+                r"^{}[_\d]*\.csv$",
+                regex::escape(file_name_start)
+            ))
+            .unwrap(),
         }
     }
 
+    /// Keep the columns of the given names (as in the header of the file) as node properties.
+    pub fn with_properties(mut self, properties: Vec<String>) -> Self {
+        self.properties = properties;
+
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
     pub fn is_match(&self, path: &PathBuf) -> bool {
         let filename = path.as_path().file_name().unwrap().to_str().unwrap();
 
         self.file_name_start.is_match(filename)
     }
 
-    pub fn add_node<Id: IdType, Ty: GraphType>(
+    /// Locate the property columns in the `header` of a node file.
+    pub fn property_indices(&self, header: &StringRecord) -> Result<Vec<usize>> {
+        self.properties
+            .iter()
+            .map(|name| {
+                header.iter().position(|h| h == name).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Property '{}' of {} is not found.", name, self.name),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Parse the node in `record`, keeping its properties, and return its id and label.
+    /// A node seen for the first time is given the next id, namely, the number of nodes
+    /// in `node_id_map`.
+    pub fn parse_node<Id: IdType>(
         &self,
         record: StringRecord,
-        g: &mut TypedGraphMap<Id, String, String, Ty>,
+        property_indices: &[usize],
         node_id_map: &mut HashMap<String, Id>,
-    ) {
+        node_properties: &mut NodeProperties<Id>,
+    ) -> (Id, Option<String>) {
         let str_id = self.name.clone() + &record[self.id_index];
 
-        let id = *node_id_map.entry(str_id).or_insert_with(|| {
-            if let Some(i) = g.max_seen_id() {
-                i.increment()
-            } else {
-                Id::new(0)
+        let next_id = Id::new(node_id_map.len());
+        let id = *node_id_map.entry(str_id).or_insert(next_id);
+
+        let label = match self.label_index {
+            Some(index) => record[index].to_owned(),
+            None => self.name.clone(),
+        };
+
+        if !property_indices.is_empty() {
+            let properties = node_properties.entry(id).or_default();
+
+            for (name, &index) in self.properties.iter().zip(property_indices) {
+                properties.insert(name.clone(), record[index].to_owned());
             }
-        });
+        }
 
-        g.add_node(id, Some(record[self.label_index].to_owned()));
+        (id, Some(label))
     }
 }
 
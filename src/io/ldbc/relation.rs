@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use csv::StringRecord;
+use regex;
 use regex::Regex;
 
 use generic::{GraphTrait, MutGraphTrait};
@@ -33,8 +34,11 @@ impl Relation {
             edge_label: edge_label.to_owned(),
             start_index,
             target_index,
-            file_name_start: Regex::new(&format!("{}{}", file_name_start, r"[_\d]*.csv")[..])
-                .unwrap(),
+            file_name_start: Regex::new(&format!(
+                r"^{}[_\d]*\.csv$",
+                regex::escape(file_name_start)
+            ))
+            .unwrap(),
         }
     }
 
@@ -44,37 +48,62 @@ impl Relation {
         self.file_name_start.is_match(filename)
     }
 
-    pub fn add_edge<Id: IdType, Ty: GraphType>(
+    /// Parse the edge in `record` and return its ends and label. If either end is not found
+    /// in `node_id_map` while its node files have been loaded (`loaded_labels`), the edge
+    /// is unresolved and `None` is returned. Otherwise, the missing node is added to `g`.
+    pub fn parse_edge<Id: IdType, Ty: GraphType>(
         &self,
         record: StringRecord,
         g: &mut TypedGraphMap<Id, String, String, Ty>,
         node_id_map: &mut HashMap<String, Id>,
-    ) {
+        loaded_labels: &HashSet<String>,
+    ) -> Option<(Id, Id, Option<String>)> {
         let start_str_id = self.start_label.clone() + &record[self.start_index];
         let target_str_id = self.target_label.clone() + &record[self.target_index];
 
-        let start_id = *node_id_map.entry(start_str_id).or_insert_with(|| {
-            let i = if let Some(i) = g.max_seen_id() {
-                i.increment()
-            } else {
-                Id::new(0)
-            };
-            g.add_node(i, Some(self.start_label.clone()));
+        let start_id = find_or_add_node(
+            &self.start_label,
+            start_str_id,
+            g,
+            node_id_map,
+            loaded_labels,
+        )?;
 
-            i
-        });
+        let target_id = find_or_add_node(
+            &self.target_label,
+            target_str_id,
+            g,
+            node_id_map,
+            loaded_labels,
+        )?;
 
-        let target_id = *node_id_map.entry(target_str_id).or_insert_with(|| {
-            let i = if let Some(i) = g.max_seen_id() {
-                i.increment()
-            } else {
-                Id::new(0)
-            };
-            g.add_node(i, Some(self.target_label.clone()));
+        Some((start_id, target_id, Some(self.edge_label.clone())))
+    }
+}
 
-            i
-        });
+fn find_or_add_node<Id: IdType, Ty: GraphType>(
+    label: &str,
+    str_id: String,
+    g: &mut TypedGraphMap<Id, String, String, Ty>,
+    node_id_map: &mut HashMap<String, Id>,
+    loaded_labels: &HashSet<String>,
+) -> Option<Id> {
+    if let Some(&id) = node_id_map.get(&str_id) {
+        return Some(id);
+    }
 
-        g.add_edge(start_id, target_id, Some(self.edge_label.clone()));
+    if loaded_labels.contains(label) {
+        return None;
     }
+
+    let id = if let Some(i) = g.max_seen_id() {
+        i.increment()
+    } else {
+        Id::new(0)
+    };
+
+    g.add_node(id, Some(label.to_owned()));
+    node_id_map.insert(str_id, id);
+
+    Some(id)
 }
@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+
+/// The number of records read from a file, and how many of them are unresolved,
+/// i.e., relations referring to unknown nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileReport {
+    path: PathBuf,
+    records: usize,
+    unresolved: usize,
+}
+
+impl FileReport {
+    pub fn new(path: PathBuf, records: usize, unresolved: usize) -> Self {
+        FileReport {
+            path,
+            records,
+            unresolved,
+        }
+    }
+
+    pub fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn num_of_records(&self) -> usize {
+        self.records
+    }
+
+    pub fn num_of_unresolved(&self) -> usize {
+        self.unresolved
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoadReport {
+    files: Vec<FileReport>,
+    implicit_nodes: usize,
+}
+
+impl LoadReport {
+    pub fn new() -> Self {
+        LoadReport::default()
+    }
+
+    pub fn add_file(&mut self, file: FileReport) {
+        self.files.push(file);
+    }
+
+    pub fn set_implicit_nodes(&mut self, implicit_nodes: usize) {
+        self.implicit_nodes = implicit_nodes;
+    }
+
+    /// Reports of all files read, in the order of reading.
+    pub fn get_files(&self) -> &[FileReport] {
+        &self.files[..]
+    }
+
+    pub fn num_of_records(&self) -> usize {
+        self.files.iter().map(|f| f.num_of_records()).sum()
+    }
+
+    pub fn num_of_unresolved(&self) -> usize {
+        self.files.iter().map(|f| f.num_of_unresolved()).sum()
+    }
+
+    /// The number of nodes created from relations, as no node file of their label is given.
+    pub fn num_of_implicit_nodes(&self) -> usize {
+        self.implicit_nodes
+    }
+}
@@ -1,181 +1,93 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::io::Result;
+use std::fs::File;
+use std::io::{Error, ErrorKind, Read, Result};
 use std::path::Path;
+use std::str::FromStr;
 
+use generic::{DuplicatePolicy, GraphTrait, MutGraphTrait};
 use generic::{GraphType, IdType};
 use graph_impl::graph_map::TypedGraphMap;
 use io::ldbc::node::Node;
 use io::ldbc::relation::Relation;
+use io::ldbc::report::{FileReport, LoadReport};
+use io::ldbc::NodeProperties;
 
 use csv::ReaderBuilder;
+use toml;
+
+const LDBC_SNB_SCHEME: &str = include_str!("snb.toml");
 
 #[derive(Debug)]
 pub struct Scheme {
     relations: Vec<Relation>,
     nodes: Vec<Node>,
     delimiter: u8,
+    duplicate_policy: DuplicatePolicy,
 }
 
 impl Scheme {
-    pub fn init() -> Self {
-        let n1 = Node::new("organisation", 0, 1, "organisation_");
-        let n2 = Node::new("place", 0, 3, "place_");
-
-        let r1 = Relation::new(
-            "comment",
-            "person",
-            "hasCreator",
-            0,
-            1,
-            "comment_hasCreator_person_",
-        );
-        let r2 = Relation::new("comment", "tag", "hasTag", 0, 1, "comment_hasTag_tag_");
-        let r3 = Relation::new(
-            "comment",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "comment_isLocatedIn_place_",
-        );
-        let r4 = Relation::new(
-            "comment",
-            "comment",
-            "replyOf",
-            0,
-            1,
-            "comment_replyOf_comment_",
-        );
-        let r5 = Relation::new("comment", "post", "replyOf", 0, 1, "comment_replyOf_post_");
-        let r6 = Relation::new(
-            "forum",
-            "post",
-            "containerOf",
-            0,
-            1,
-            "forum_containerOf_post_",
-        );
-        let r7 = Relation::new(
-            "forum",
-            "post",
-            "containerOf",
-            0,
-            1,
-            "forum_containerOf_post_",
-        );
-        let r8 = Relation::new(
-            "forum",
-            "person",
-            "hasMember",
-            0,
-            1,
-            "forum_hasMember_person_",
-        );
-        let r9 = Relation::new(
-            "forum",
-            "person",
-            "hasModerator",
-            0,
-            1,
-            "forum_hasModerator_person_",
-        );
-        let r10 = Relation::new(
-            "forum",
-            "person",
-            "hasModerator",
-            0,
-            1,
-            "forum_hasModerator_person_",
-        );
-        let r11 = Relation::new("forum", "tag", "hasTag", 0, 1, "forum_hasTag_tag_");
-        let r12 = Relation::new(
-            "organisation",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "organisation_isLocatedIn_place_",
-        );
-        let r13 = Relation::new(
-            "person",
-            "tag",
-            "hasInterest",
-            0,
-            1,
-            "person_hasInterest_tag_",
-        );
-        let r14 = Relation::new(
-            "person",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "person_isLocatedIn_place_",
-        );
-        let r15 = Relation::new("person", "person", "knows", 0, 1, "person_knows_person_");
-        let r16 = Relation::new("person", "comment", "likes", 0, 1, "person_likes_comment_");
-        let r17 = Relation::new("person", "post", "likes", 0, 1, "person_likes_post_");
-        let r18 = Relation::new(
-            "person",
-            "organisation",
-            "studyAt",
-            0,
-            1,
-            "person_studyAt_organisation_",
-        );
-        let r19 = Relation::new(
-            "person",
-            "organisation",
-            "workAt",
-            0,
-            1,
-            "person_workAt_organisation_",
-        );
-        let r20 = Relation::new("place", "place", "isPartOf", 0, 1, "place_isPartOf_place_");
-        let r21 = Relation::new(
-            "post",
-            "person",
-            "hasCreator",
-            0,
-            1,
-            "post_hasCreator_person_",
-        );
-        let r22 = Relation::new("post", "tag", "hasTag", 0, 1, "post_hasTag_tag_");
-        let r23 = Relation::new(
-            "post",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "post_isLocatedIn_place_",
-        );
-        let r24 = Relation::new("tag", "tagclass", "hasType", 0, 1, "tag_hasType_tagclass_");
-        let r25 = Relation::new(
-            "tagclass",
-            "tagclass",
-            "isSubclassOf",
-            0,
-            1,
-            "tagclass_isSubclassOf_tagclass_",
-        );
-
+    /// Create an empty scheme, whose files are separated by `delimiter`.
+    pub fn new(delimiter: u8) -> Self {
         Scheme {
-            relations: vec![
-                r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11, r12, r13, r14, r15, r16, r17, r18,
-                r19, r20, r21, r22, r23, r24, r25,
-            ],
-            nodes: vec![n1, n2],
-            delimiter: b'|',
+            relations: Vec::new(),
+            nodes: Vec::new(),
+            delimiter,
+            duplicate_policy: DuplicatePolicy::Overwrite,
         }
     }
 
+    /// The scheme of LDBC SNB datasets, as described in `snb.toml`.
+    pub fn init() -> Self {
+        LDBC_SNB_SCHEME.parse().unwrap()
+    }
+
+    /// Load a scheme from a description file in TOML, see `snb.toml` for an example.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut content = String::new();
+        File::open(path)?.read_to_string(&mut content)?;
+
+        content.parse()
+    }
+
+    pub fn add_node(&mut self, node: Node) -> &mut Self {
+        self.nodes.push(node);
+
+        self
+    }
+
+    pub fn add_relation(&mut self, relation: Relation) -> &mut Self {
+        self.relations.push(relation);
+
+        self
+    }
+
+    /// How the nodes and edges repeated in the files are treated. By default, their labels
+    /// are overwritten.
+    pub fn with_duplicate_policy(&mut self, duplicate_policy: DuplicatePolicy) -> &mut Self {
+        self.duplicate_policy = duplicate_policy;
+
+        self
+    }
+
     pub fn from_path<Id: IdType, Ty: GraphType, P: AsRef<Path>>(
         &self,
         path: P,
     ) -> Result<TypedGraphMap<Id, String, String, Ty>> {
+        self.load(path).map(LdbcGraph::into_graph)
+    }
+
+    /// Load the graph, together with the node properties and a report of every file read.
+    ///
+    /// A relation referring to a node that is not found in any node file of its label is
+    /// skipped and reported as unresolved. If no node file of that label is present, the
+    /// node is created instead, labeled with the label given in the relation.
+    pub fn load<Id: IdType, Ty: GraphType, P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<LdbcGraph<Id, Ty>> {
         if !path.as_ref().is_dir() {
-            panic!("path must be a dir")
+            return Err(Error::new(ErrorKind::InvalidInput, "path must be a dir"));
         }
 
         let mut files_in_dir = Vec::new();
@@ -188,10 +100,15 @@ impl Scheme {
             }
         }
 
+        files_in_dir.sort();
+
         let mut g = TypedGraphMap::new();
         let mut node_id_map = HashMap::<String, Id>::new();
+        let mut node_properties = NodeProperties::new();
+        let mut loaded_labels = HashSet::new();
+        let mut report = LoadReport::new();
 
-        info!("ldbc::Scheme::from_path - Adding nodes.");
+        info!("ldbc::Scheme::load - Adding nodes.");
         for node in self.nodes.iter() {
             for path in files_in_dir.iter() {
                 if node.is_match(path) {
@@ -201,16 +118,41 @@ impl Scheme {
                         .delimiter(self.delimiter)
                         .from_path(path.clone())?;
 
+                    let property_indices = node.property_indices(rdr.headers()?)?;
+                    let mut nodes = Vec::new();
+
                     for result in rdr.records() {
                         let record = result?;
 
-                        node.add_node::<Id, Ty>(record, &mut g, &mut node_id_map);
+                        nodes.push(node.parse_node(
+                            record,
+                            &property_indices,
+                            &mut node_id_map,
+                            &mut node_properties,
+                        ));
                     }
+
+                    let records = nodes.len();
+                    let stats = g.extend_nodes(nodes, self.duplicate_policy)?;
+
+                    if stats.duplicates() > 0 {
+                        warn!(
+                            "ldbc::Scheme::load - {} of {} records in '{}' are duplicates.",
+                            stats.duplicates(),
+                            records,
+                            path.to_str().unwrap()
+                        );
+                    }
+
+                    loaded_labels.insert(node.get_name().to_owned());
+                    report.add_file(FileReport::new(path.clone(), records, 0));
                 }
             }
         }
 
-        info!("ldbc::Scheme::from_path - Adding relations.");
+        info!("ldbc::Scheme::load - Adding relations.");
+        let num_of_nodes = g.node_count();
+
         for relation in self.relations.iter() {
             for path in files_in_dir.iter() {
                 if relation.is_match(path) {
@@ -220,15 +162,183 @@ impl Scheme {
                         .delimiter(self.delimiter)
                         .from_path(path.clone())?;
 
+                    let mut records = 0;
+                    let mut edges = Vec::new();
+
                     for result in rdr.records() {
                         let record = result?;
 
-                        relation.add_edge::<Id, Ty>(record, &mut g, &mut node_id_map);
+                        if let Some(edge) =
+                            relation.parse_edge(record, &mut g, &mut node_id_map, &loaded_labels)
+                        {
+                            edges.push(edge);
+                        }
+                        records += 1;
+                    }
+
+                    let unresolved = records - edges.len();
+                    let stats = g.extend_edges(edges, self.duplicate_policy)?;
+
+                    if stats.duplicates() > 0 {
+                        warn!(
+                            "ldbc::Scheme::load - {} of {} records in '{}' are duplicates.",
+                            stats.duplicates(),
+                            records,
+                            path.to_str().unwrap()
+                        );
                     }
+
+                    if unresolved > 0 {
+                        warn!(
+                            "ldbc::Scheme::load - {} of {} records in '{}' are unresolved.",
+                            unresolved,
+                            records,
+                            path.to_str().unwrap()
+                        );
+                    }
+
+                    report.add_file(FileReport::new(path.clone(), records, unresolved));
                 }
             }
         }
 
-        Ok(g)
+        let implicit_nodes = g.node_count() - num_of_nodes;
+        if implicit_nodes > 0 {
+            warn!(
+                "ldbc::Scheme::load - {} nodes are created from relations only.",
+                implicit_nodes
+            );
+        }
+        report.set_implicit_nodes(implicit_nodes);
+
+        Ok(LdbcGraph {
+            graph: g,
+            properties: node_properties,
+            report,
+        })
+    }
+}
+
+/// A graph loaded by `Scheme::load`.
+pub struct LdbcGraph<Id: IdType, Ty: GraphType> {
+    graph: TypedGraphMap<Id, String, String, Ty>,
+    properties: NodeProperties<Id>,
+    report: LoadReport,
+}
+
+impl<Id: IdType, Ty: GraphType> LdbcGraph<Id, Ty> {
+    pub fn get_graph(&self) -> &TypedGraphMap<Id, String, String, Ty> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> TypedGraphMap<Id, String, String, Ty> {
+        self.graph
+    }
+
+    pub fn get_properties(&self) -> &NodeProperties<Id> {
+        &self.properties
+    }
+
+    /// Lookup a property of a node by the name of its column.
+    pub fn get_node_property(&self, id: Id, name: &str) -> Option<&str> {
+        self.properties
+            .get(&id)
+            .and_then(|p| p.get(name))
+            .map(|p| &p[..])
+    }
+
+    pub fn get_report(&self) -> &LoadReport {
+        &self.report
+    }
+}
+
+impl FromStr for Scheme {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let description: SchemeDescription =
+            toml::from_str(s).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+        if description.delimiter.len() != 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid delimiter {}.", description.delimiter),
+            ));
+        }
+
+        let mut scheme = Scheme::new(description.delimiter.as_bytes()[0]);
+
+        for node in description.nodes {
+            let file_name_start = node
+                .file_name_start
+                .clone()
+                .unwrap_or_else(|| format!("{}_", node.name));
+
+            scheme.add_node(
+                Node::new(
+                    &node.name,
+                    node.id_index,
+                    node.label_index,
+                    &file_name_start,
+                )
+                .with_properties(node.properties),
+            );
+        }
+
+        for relation in description.relations {
+            let file_name_start = relation.file_name_start.clone().unwrap_or_else(|| {
+                format!(
+                    "{}_{}_{}_",
+                    relation.start_label, relation.edge_label, relation.target_label
+                )
+            });
+
+            scheme.add_relation(Relation::new(
+                &relation.start_label,
+                &relation.target_label,
+                &relation.edge_label,
+                relation.start_index,
+                relation.target_index,
+                &file_name_start,
+            ));
+        }
+
+        Ok(scheme)
     }
 }
+
+#[derive(Debug, Deserialize)]
+struct SchemeDescription {
+    delimiter: String,
+    #[serde(default)]
+    nodes: Vec<NodeDescription>,
+    #[serde(default)]
+    relations: Vec<RelationDescription>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NodeDescription {
+    name: String,
+    #[serde(default)]
+    id_index: usize,
+    label_index: Option<usize>,
+    #[serde(default)]
+    properties: Vec<String>,
+    file_name_start: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RelationDescription {
+    start_label: String,
+    target_label: String,
+    edge_label: String,
+    #[serde(default)]
+    start_index: usize,
+    #[serde(default = "default_target_index")]
+    target_index: usize,
+    file_name_start: Option<String>,
+}
+
+fn default_target_index() -> usize {
+    1
+}
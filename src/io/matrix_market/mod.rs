@@ -0,0 +1,53 @@
+//! Reading and writing graphs in the Matrix Market coordinate format.
+//!
+//! ```text
+//! %%MatrixMarket matrix coordinate <pattern|integer|real> <general|symmetric>
+//! % comments
+//! rows cols entries
+//! i j [value]
+//! ```
+//!
+//! Indices are 1-based. Each entry `(i, j)` becomes an edge from `i - 1` to `j - 1`, and
+//! its value (if any) becomes the edge label. A `symmetric` matrix stores only one
+//! triangle, which is expanded into edges of both directions.
+
+pub mod reader;
+pub mod writer;
+
+use std::fmt::Display;
+use std::hash::Hash;
+use std::io::Result;
+use std::path::Path;
+use std::str::FromStr;
+
+use generic::{GraphType, IdType};
+use graph_impl::TypedStaticGraph;
+use io::matrix_market::reader::MatrixMarketReader;
+use io::matrix_market::writer::MatrixMarketWriter;
+
+pub fn read_from_matrix_market<Id, NL, EL, Ty, P>(
+    path: P,
+) -> Result<TypedStaticGraph<Id, NL, EL, Ty>>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq + FromStr,
+    Ty: GraphType,
+    P: AsRef<Path>,
+{
+    MatrixMarketReader::new(path).read()
+}
+
+pub fn write_to_matrix_market<Id, NL, EL, Ty, P>(
+    g: &TypedStaticGraph<Id, NL, EL, Ty>,
+    path: P,
+) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq + Display,
+    Ty: GraphType,
+    P: AsRef<Path>,
+{
+    MatrixMarketWriter::new(g, path).write()
+}
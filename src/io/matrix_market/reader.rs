@@ -0,0 +1,218 @@
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufRead, BufReader, Result};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use generic::MutMapTrait;
+use generic::{GraphType, IdType};
+use graph_impl::static_graph::EdgeVec;
+use graph_impl::TypedStaticGraph;
+use io::{invalid_data, parse_id, parse_label};
+use map::SetMap;
+
+pub struct MatrixMarketReader<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> {
+    path: PathBuf,
+    id_type: PhantomData<Id>,
+    nl_type: PhantomData<NL>,
+    el_type: PhantomData<EL>,
+    graph_type: PhantomData<Ty>,
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType> MatrixMarketReader<Id, NL, EL, Ty> {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        MatrixMarketReader {
+            path: path.as_ref().to_path_buf(),
+            id_type: PhantomData,
+            nl_type: PhantomData,
+            el_type: PhantomData,
+            graph_type: PhantomData,
+        }
+    }
+}
+
+impl<Id, NL, EL, Ty> MatrixMarketReader<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq + FromStr,
+    Ty: GraphType,
+{
+    /// Read the matrix as a graph of `max(rows, cols)` nodes.
+    ///
+    /// A `general` matrix read into an undirected graph is symmetrized, keeping the value
+    /// of the first entry seen for each pair. Self-loops are dropped from undirected graphs.
+    pub fn read(&self) -> Result<TypedStaticGraph<Id, NL, EL, Ty>> {
+        info!(
+            "matrix_market::Reader::read - Reading graph from {}",
+            self.path.as_path().to_str().unwrap()
+        );
+
+        let reader = BufReader::new(File::open(self.path.as_path())?);
+        let mut lines = reader.lines();
+
+        let banner = match lines.next() {
+            Some(line) => line?.to_lowercase(),
+            None => return Err(invalid_data("Matrix Market banner is missing.")),
+        };
+        let banner: Vec<&str> = banner.split_whitespace().collect();
+
+        if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+            return Err(invalid_data(format!(
+                "Invalid Matrix Market banner '{}'.",
+                banner.join(" ")
+            )));
+        }
+
+        if banner[2] != "coordinate" {
+            return Err(invalid_data(format!(
+                "Unsupported Matrix Market format '{}'.",
+                banner[2]
+            )));
+        }
+
+        let has_value = match banner[3] {
+            "pattern" => false,
+            "integer" | "real" => true,
+            other => {
+                return Err(invalid_data(format!(
+                    "Unsupported Matrix Market field '{}'.",
+                    other
+                )))
+            }
+        };
+
+        let is_symmetric = match banner[4] {
+            "general" => false,
+            "symmetric" => true,
+            other => {
+                return Err(invalid_data(format!(
+                    "Unsupported Matrix Market symmetry '{}'.",
+                    other
+                )))
+            }
+        };
+
+        let mut lines = lines.filter(|line| match *line {
+            Ok(ref line) => !line.starts_with('%') && !line.trim().is_empty(),
+            Err(_) => true,
+        });
+
+        let size = match lines.next() {
+            Some(line) => line?,
+            None => return Err(invalid_data("Matrix Market size line is missing.")),
+        };
+        let size: Vec<&str> = size.split_whitespace().collect();
+
+        if size.len() != 3 {
+            return Err(invalid_data(format!(
+                "Invalid Matrix Market size line '{}'.",
+                size.join(" ")
+            )));
+        }
+
+        let rows = parse_id::<Id>(size[0])?.id();
+        let cols = parse_id::<Id>(size[1])?.id();
+        let num_entries: usize = size[2]
+            .parse()
+            .map_err(|_| invalid_data(format!("Invalid number of entries '{}'.", size[2])))?;
+        let num_nodes = rows.max(cols);
+
+        let mut edge_label_map = SetMap::new();
+        let mut edges = Vec::with_capacity(if is_symmetric || !Ty::is_directed() {
+            2 * num_entries
+        } else {
+            num_entries
+        });
+
+        let mut entries = 0;
+
+        for line in lines {
+            let line = line?;
+            entries += 1;
+            let mut tokens = line.split_whitespace();
+
+            let (row, col) = match (tokens.next(), tokens.next()) {
+                (Some(row), Some(col)) => (parse_id::<Id>(row)?.id(), parse_id::<Id>(col)?.id()),
+                _ => return Err(invalid_data(format!("Invalid entry '{}'.", line))),
+            };
+
+            if row == 0 || row > rows || col == 0 || col > cols {
+                return Err(invalid_data(format!(
+                    "Entry ({}, {}) is out of range.",
+                    row, col
+                )));
+            }
+
+            let label = if has_value {
+                match tokens.next() {
+                    Some(value) => Id::new(edge_label_map.add_item(parse_label::<EL>(value)?)),
+                    None => return Err(invalid_data(format!("Missing value in '{}'.", line))),
+                }
+            } else {
+                Id::max_value()
+            };
+
+            let (start, target) = (Id::new(row - 1), Id::new(col - 1));
+
+            if start == target && !Ty::is_directed() {
+                warn!(
+                    "matrix_market::Reader::read - Dropping self-loop on node {}.",
+                    start
+                );
+
+                continue;
+            }
+
+            edges.push((start, target, label));
+
+            if start != target && (is_symmetric || !Ty::is_directed()) {
+                edges.push((target, start, label));
+            }
+        }
+
+        if entries != num_entries {
+            return Err(invalid_data(format!(
+                "Expect {} entries, found {}.",
+                num_entries, entries
+            )));
+        }
+
+        edges.sort_by_key(|&(s, t, _)| (s, t));
+        edges.dedup_by_key(|&mut (s, t, _)| (s, t));
+
+        let num_edges = if Ty::is_directed() {
+            edges.len()
+        } else {
+            edges.len() >> 1
+        };
+
+        let in_edge_vec = if Ty::is_directed() {
+            let in_edges = edges.iter().map(|&(s, t, _)| (t, s)).collect();
+
+            Some(EdgeVec::from_edges(num_nodes, in_edges))
+        } else {
+            None
+        };
+
+        let edge_vec = if has_value {
+            EdgeVec::from_labeled_edges(num_nodes, edges)
+        } else {
+            EdgeVec::from_edges(
+                num_nodes,
+                edges.into_iter().map(|(s, t, _)| (s, t)).collect(),
+            )
+        };
+
+        Ok(TypedStaticGraph::from_raw(
+            num_nodes,
+            num_edges,
+            edge_vec,
+            in_edge_vec,
+            None,
+            SetMap::new(),
+            edge_label_map,
+        ))
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Result, Write};
+use std::path::{Path, PathBuf};
+
+use generic::{GraphLabelTrait, GraphTrait, MapTrait};
+use generic::{GraphType, IdType};
+use graph_impl::TypedStaticGraph;
+use io::invalid_data;
+
+pub struct MatrixMarketWriter<'a, Id, NL, EL, Ty>
+where
+    Id: 'a + IdType,
+    NL: 'a + Hash + Eq,
+    EL: 'a + Hash + Eq + Display,
+    Ty: 'a + GraphType,
+{
+    g: &'a TypedStaticGraph<Id, NL, EL, Ty>,
+    path: PathBuf,
+}
+
+impl<'a, Id, NL, EL, Ty> MatrixMarketWriter<'a, Id, NL, EL, Ty>
+where
+    Id: 'a + IdType,
+    NL: 'a + Hash + Eq,
+    EL: 'a + Hash + Eq + Display,
+    Ty: 'a + GraphType,
+{
+    pub fn new<P: AsRef<Path>>(g: &'a TypedStaticGraph<Id, NL, EL, Ty>, path: P) -> Self {
+        MatrixMarketWriter {
+            g,
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Write a directed graph as a `general` matrix and an undirected graph as a
+    /// `symmetric` one (lower triangle only). Edge labels are written as values, with
+    /// field `integer` if all of them are integers and `real` otherwise; node labels
+    /// are not kept.
+    pub fn write(&self) -> Result<()> {
+        info!(
+            "matrix_market::Writer::write - Writing graph to {}",
+            self.path.as_path().to_str().unwrap()
+        );
+
+        let g = self.g;
+        let edge_vec = g.get_edge_vec();
+        let edge_labels = edge_vec.get_labels();
+
+        let mut entries = Vec::new();
+
+        for start in g.node_indices() {
+            let offset = edge_vec.get_offsets()[start.id()];
+
+            for (i, &target) in edge_vec.neighbors(start).iter().enumerate() {
+                if !g.is_directed() && start < target {
+                    continue;
+                }
+
+                let value = if edge_labels.is_empty() {
+                    None
+                } else {
+                    match g
+                        .get_edge_label_map()
+                        .get_item(edge_labels[offset + i].id())
+                    {
+                        Some(label) => Some(label.to_string()),
+                        None => {
+                            return Err(invalid_data(format!(
+                                "Edge ({}, {}) has no label.",
+                                start, target
+                            )));
+                        }
+                    }
+                };
+
+                entries.push((start, target, value));
+            }
+        }
+
+        let field = if edge_labels.is_empty() {
+            "pattern"
+        } else if entries
+            .iter()
+            .all(|(_, _, v)| v.as_ref().unwrap().parse::<i64>().is_ok())
+        {
+            "integer"
+        } else if let Some((start, target, value)) = entries
+            .iter()
+            .find(|(_, _, v)| v.as_ref().unwrap().parse::<f64>().is_err())
+        {
+            return Err(invalid_data(format!(
+                "Label '{}' of edge ({}, {}) is neither an integer nor a real.",
+                value.as_ref().unwrap(),
+                start,
+                target
+            )));
+        } else {
+            "real"
+        };
+
+        let symmetry = if g.is_directed() {
+            "general"
+        } else {
+            "symmetric"
+        };
+
+        let mut writer = BufWriter::new(File::create(self.path.as_path())?);
+
+        writeln!(
+            writer,
+            "%%MatrixMarket matrix coordinate {} {}",
+            field, symmetry
+        )?;
+        writeln!(
+            writer,
+            "{} {} {}",
+            g.node_count(),
+            g.node_count(),
+            entries.len()
+        )?;
+
+        for (start, target, value) in entries {
+            match value {
+                Some(value) => {
+                    writeln!(writer, "{} {} {}", start.id() + 1, target.id() + 1, value)?
+                }
+                None => writeln!(writer, "{} {}", start.id() + 1, target.id() + 1)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+//! Reading and writing graphs in the METIS graph format.
+//!
+//! A METIS file starts with a header `n m [fmt [ncon]]`, followed by exactly `n` lines,
+//! one for each vertex `1 ..= n`:
+//!
+//! ```text
+//! [size] [w_1 ... w_ncon] v_1 [ew_1] v_2 [ew_2] ...
+//! ```
+//!
+//! where `fmt` is a three-digit flag telling whether vertex sizes, vertex weights and
+//! edge weights are present. Vertex weights are mapped to node labels (only the first
+//! of `ncon` weights is kept) and edge weights are mapped to edge labels. Lines starting
+//! with `%` are comments.
+
+pub mod reader;
+pub mod writer;
+
+use std::fmt::Display;
+use std::hash::Hash;
+use std::io::Result;
+use std::path::Path;
+use std::str::FromStr;
+
+use generic::IdType;
+use graph_impl::TypedUnStaticGraph;
+use io::metis::reader::MetisReader;
+use io::metis::writer::MetisWriter;
+
+pub fn read_from_metis<Id, NL, EL, P>(path: P) -> Result<TypedUnStaticGraph<Id, NL, EL>>
+where
+    Id: IdType,
+    NL: Hash + Eq + FromStr,
+    EL: Hash + Eq + FromStr,
+    P: AsRef<Path>,
+{
+    MetisReader::new(path).read()
+}
+
+pub fn write_to_metis<Id, NL, EL, P>(g: &TypedUnStaticGraph<Id, NL, EL>, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq + Display,
+    EL: Hash + Eq + Display,
+    P: AsRef<Path>,
+{
+    MetisWriter::new(g, path).write()
+}
@@ -0,0 +1,184 @@
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufRead, BufReader, Result};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use generic::IdType;
+use generic::MutMapTrait;
+use graph_impl::static_graph::EdgeVec;
+use graph_impl::TypedUnStaticGraph;
+use io::{invalid_data, parse_id, parse_label};
+use map::SetMap;
+
+pub struct MetisReader<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> {
+    path: PathBuf,
+    id_type: PhantomData<Id>,
+    nl_type: PhantomData<NL>,
+    el_type: PhantomData<EL>,
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq> MetisReader<Id, NL, EL> {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        MetisReader {
+            path: path.as_ref().to_path_buf(),
+            id_type: PhantomData,
+            nl_type: PhantomData,
+            el_type: PhantomData,
+        }
+    }
+}
+
+impl<Id, NL, EL> MetisReader<Id, NL, EL>
+where
+    Id: IdType,
+    NL: Hash + Eq + FromStr,
+    EL: Hash + Eq + FromStr,
+{
+    pub fn read(&self) -> Result<TypedUnStaticGraph<Id, NL, EL>> {
+        info!(
+            "metis::Reader::read - Reading graph from {}",
+            self.path.as_path().to_str().unwrap()
+        );
+
+        let reader = BufReader::new(File::open(self.path.as_path())?);
+        let mut lines = reader.lines().filter(|line| match *line {
+            Ok(ref line) => !line.starts_with('%'),
+            Err(_) => true,
+        });
+
+        let header = match lines.next() {
+            Some(line) => line?,
+            None => return Err(invalid_data("METIS header is missing.")),
+        };
+        let header: Vec<&str> = header.split_whitespace().collect();
+
+        if header.len() < 2 || header.len() > 4 {
+            return Err(invalid_data(format!(
+                "Invalid METIS header '{}'.",
+                header.join(" ")
+            )));
+        }
+
+        let num_nodes = parse_id::<Id>(header[0])?.id();
+        let num_edges: usize = header[1]
+            .parse()
+            .map_err(|_| invalid_data(format!("Invalid number of edges '{}'.", header[1])))?;
+        let fmt = format!("{:0>3}", header.get(2).unwrap_or(&"0"));
+
+        if fmt.len() != 3 || fmt.chars().any(|c| c != '0' && c != '1') {
+            return Err(invalid_data(format!("Invalid METIS fmt '{}'.", header[2])));
+        }
+
+        let flags: Vec<bool> = fmt.chars().map(|c| c == '1').collect();
+        let (has_size, has_node_weight, has_edge_weight) = (flags[0], flags[1], flags[2]);
+        let ncon = match header.get(3) {
+            Some(ncon) => ncon
+                .parse()
+                .map_err(|_| invalid_data(format!("Invalid METIS ncon '{}'.", ncon)))?,
+            None if has_node_weight => 1,
+            None => 0,
+        };
+
+        if (ncon > 0) != has_node_weight {
+            return Err(invalid_data(format!(
+                "METIS ncon {} does not match fmt '{}'.",
+                ncon, fmt
+            )));
+        }
+
+        let mut node_label_map = SetMap::new();
+        let mut edge_label_map = SetMap::new();
+        let mut node_labels = Vec::with_capacity(if has_node_weight { num_nodes } else { 0 });
+        let mut edges = Vec::with_capacity(2 * num_edges);
+        let mut edge_labels = Vec::with_capacity(if has_edge_weight { 2 * num_edges } else { 0 });
+
+        for node in 0..num_nodes {
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => {
+                    return Err(invalid_data(format!(
+                        "Expect {} vertex lines, found {}.",
+                        num_nodes, node
+                    )))
+                }
+            };
+
+            let mut tokens = line.split_whitespace();
+
+            if has_size {
+                tokens.next();
+            }
+
+            for i in 0..ncon {
+                let weight = tokens.next().ok_or_else(|| {
+                    invalid_data(format!("Missing weight of vertex {}.", node + 1))
+                })?;
+
+                if i == 0 {
+                    let label = parse_label::<NL>(weight)?;
+                    node_labels.push(Id::new(node_label_map.add_item(label)));
+                }
+            }
+
+            while let Some(token) = tokens.next() {
+                let neighbor = parse_id::<Id>(token)?.id();
+
+                if neighbor == 0 || neighbor > num_nodes {
+                    return Err(invalid_data(format!(
+                        "Vertex {} is out of range [1, {}].",
+                        neighbor, num_nodes
+                    )));
+                }
+
+                edges.push((Id::new(node), Id::new(neighbor - 1)));
+
+                if has_edge_weight {
+                    let weight = tokens.next().ok_or_else(|| {
+                        invalid_data(format!(
+                            "Missing weight of edge ({}, {}).",
+                            node + 1,
+                            neighbor
+                        ))
+                    })?;
+                    let label = parse_label::<EL>(weight)?;
+                    edge_labels.push(Id::new(edge_label_map.add_item(label)));
+                }
+            }
+        }
+
+        if edges.len() != 2 * num_edges {
+            return Err(invalid_data(format!(
+                "Expect {} edges, found {} adjacency entries.",
+                num_edges,
+                edges.len()
+            )));
+        }
+
+        let edge_vec = if has_edge_weight {
+            let edges = edges
+                .into_iter()
+                .zip(edge_labels)
+                .map(|((s, t), l)| (s, t, l))
+                .collect();
+            EdgeVec::from_labeled_edges(num_nodes, edges)
+        } else {
+            EdgeVec::from_edges(num_nodes, edges)
+        };
+
+        Ok(TypedUnStaticGraph::from_raw(
+            num_nodes,
+            num_edges,
+            edge_vec,
+            None,
+            if has_node_weight {
+                Some(node_labels)
+            } else {
+                None
+            },
+            node_label_map,
+            edge_label_map,
+        ))
+    }
+}
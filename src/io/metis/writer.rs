@@ -0,0 +1,98 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Result, Write};
+use std::path::{Path, PathBuf};
+
+use generic::IdType;
+use generic::{GraphLabelTrait, GraphTrait, MapTrait, NodeTrait};
+use graph_impl::TypedUnStaticGraph;
+use io::invalid_data;
+
+pub struct MetisWriter<'a, Id, NL, EL>
+where
+    Id: 'a + IdType,
+    NL: 'a + Hash + Eq + Display,
+    EL: 'a + Hash + Eq + Display,
+{
+    g: &'a TypedUnStaticGraph<Id, NL, EL>,
+    path: PathBuf,
+}
+
+impl<'a, Id, NL, EL> MetisWriter<'a, Id, NL, EL>
+where
+    Id: 'a + IdType,
+    NL: 'a + Hash + Eq + Display,
+    EL: 'a + Hash + Eq + Display,
+{
+    pub fn new<P: AsRef<Path>>(g: &'a TypedUnStaticGraph<Id, NL, EL>, path: P) -> Self {
+        MetisWriter {
+            g,
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Write the graph, with node and edge labels (if any) as vertex and edge weights.
+    /// Either every node (edge) is labeled, or none of them.
+    pub fn write(&self) -> Result<()> {
+        info!(
+            "metis::Writer::write - Writing graph to {}",
+            self.path.as_path().to_str().unwrap()
+        );
+
+        let g = self.g;
+        let edge_vec = g.get_edge_vec();
+        let edge_labels = edge_vec.get_labels();
+        let has_node_weight = g.nodes().any(|n| n.get_label_id().is_some());
+        let has_edge_weight = !edge_labels.is_empty();
+
+        let mut writer = BufWriter::new(File::create(self.path.as_path())?);
+
+        write!(writer, "{} {}", g.node_count(), g.edge_count())?;
+        if has_node_weight || has_edge_weight {
+            write!(
+                writer,
+                " 0{}{}",
+                has_node_weight as u8, has_edge_weight as u8
+            )?;
+        }
+        writeln!(writer)?;
+
+        for node in g.node_indices() {
+            let mut fields = Vec::new();
+
+            if has_node_weight {
+                match g.get_node_label(node) {
+                    Some(label) => fields.push(label.to_string()),
+                    None => {
+                        return Err(invalid_data(format!("Node {} has no label.", node)));
+                    }
+                }
+            }
+
+            let start = edge_vec.get_offsets()[node.id()];
+
+            for (i, neighbor) in edge_vec.neighbors(node).iter().enumerate() {
+                fields.push((neighbor.id() + 1).to_string());
+
+                if has_edge_weight {
+                    let label_id = edge_labels[start + i];
+
+                    match g.get_edge_label_map().get_item(label_id.id()) {
+                        Some(label) => fields.push(label.to_string()),
+                        None => {
+                            return Err(invalid_data(format!(
+                                "Edge ({}, {}) has no label.",
+                                node, neighbor
+                            )));
+                        }
+                    }
+                }
+            }
+
+            writeln!(writer, "{}", fields.join(" "))?;
+        }
+
+        Ok(())
+    }
+}
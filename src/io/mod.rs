@@ -1,6 +1,36 @@
 pub mod csv;
+pub mod json;
 pub mod ldbc;
+pub mod matrix_market;
+pub mod metis;
 pub mod serde;
 
 pub use io::csv::{read_from_csv, write_to_csv};
+pub use io::json::{read_from_json, write_to_json, JsonFormat};
 pub use io::ldbc::read_ldbc_from_path;
+pub use io::matrix_market::{read_from_matrix_market, write_to_matrix_market};
+pub use io::metis::{read_from_metis, write_to_metis};
+
+use std::error::Error;
+use std::io;
+use std::str::FromStr;
+
+use generic::IdType;
+
+fn invalid_data<E: Into<Box<dyn Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// Parse a node id, checking that it fits into `Id`.
+fn parse_id<Id: IdType>(token: &str) -> io::Result<Id> {
+    match token.parse::<usize>() {
+        Ok(id) if id <= Id::max_value().id() => Ok(Id::new(id)),
+        _ => Err(invalid_data(format!("Invalid node id '{}'.", token))),
+    }
+}
+
+fn parse_label<L: FromStr>(token: &str) -> io::Result<L> {
+    token
+        .parse()
+        .map_err(|_| invalid_data(format!("Invalid label '{}'.", token)))
+}
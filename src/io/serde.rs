@@ -1,11 +1,23 @@
+use std::error::Error;
+use std::fmt;
 use std::fs::File;
-use std::io::{BufReader, BufWriter};
+use std::hash::Hash;
+use std::io;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::mem::size_of;
 use std::path::Path;
 
 use serde::{de, ser};
 
+use bincode;
 use bincode::Result;
-use bincode::{deserialize_from, serialize_into, Infinite};
+use bincode::{deserialize, deserialize_from, serialize, serialize_into, Bounded, Infinite};
+use crc::crc32;
+
+use error;
+use generic::{GraphType, IdType, Void};
+use graph_impl::Graph;
+use graph_impl::{TypedGraphMap, TypedStaticGraph};
 
 pub struct Serializer;
 pub struct Deserializer;
@@ -47,3 +59,334 @@ impl Deserialize for Deserializer {
         deserialize_from(&mut reader, Infinite)
     }
 }
+
+/// The magic number at the beginning of every graph file.
+pub const MAGIC: [u8; 8] = *b"RGRAPH\0\0";
+
+/// The version of the graph file format written by `export_graph`.
+pub const VERSION: u32 = 1;
+
+/// Upper bound of the size of a serialized `Header`.
+const MAX_HEADER_SIZE: u64 = 4096;
+
+/// A name of a label type, recorded in graph files to tell label types apart. Unlike
+/// `std::any::type_name`, it must not change across compilers and versions of the crate.
+/// Implement it to store graphs with labels of a custom type.
+pub trait TypeTag {
+    const TYPE_TAG: &'static str;
+}
+
+macro_rules! impl_type_tag {
+    ($($ty:ty => $tag:expr),*) => {
+        $(
+            impl TypeTag for $ty {
+                const TYPE_TAG: &'static str = $tag;
+            }
+        )*
+    };
+}
+
+impl_type_tag!(
+    Void => "void", bool => "bool", char => "char", String => "string",
+    u8 => "u8", u16 => "u16", u32 => "u32", u64 => "u64", usize => "usize",
+    i8 => "i8", i16 => "i16", i32 => "i32", i64 => "i64", isize => "isize"
+);
+
+/// What a graph file contains, which must match the graph type it is loaded into.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Description {
+    implementation: Graph,
+    id_size: u8,
+    is_directed: bool,
+    node_label_type: String,
+    edge_label_type: String,
+}
+
+impl Description {
+    pub fn new<Id, NL, EL, Ty>(implementation: Graph) -> Self
+    where
+        Id: IdType,
+        NL: TypeTag,
+        EL: TypeTag,
+        Ty: GraphType,
+    {
+        Description {
+            implementation,
+            id_size: size_of::<Id>() as u8,
+            is_directed: Ty::is_directed(),
+            node_label_type: NL::TYPE_TAG.to_owned(),
+            edge_label_type: EL::TYPE_TAG.to_owned(),
+        }
+    }
+
+    pub fn get_implementation(&self) -> Graph {
+        self.implementation
+    }
+
+    /// The size of the id type in bytes.
+    pub fn get_id_size(&self) -> usize {
+        self.id_size as usize
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.is_directed
+    }
+
+    pub fn get_node_label_type(&self) -> &str {
+        &self.node_label_type
+    }
+
+    pub fn get_edge_label_type(&self) -> &str {
+        &self.edge_label_type
+    }
+}
+
+/// Graph types that can be written to and loaded from graph files.
+pub trait Describe {
+    fn describe() -> Description;
+
+    /// Check the consistency of a loaded graph, which is only known to be well-formed.
+    fn check(&self) -> error::Result<()> {
+        Ok(())
+    }
+}
+
+impl<Id, NL, EL, Ty> Describe for TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + TypeTag,
+    EL: Hash + Eq + TypeTag,
+    Ty: GraphType,
+{
+    fn describe() -> Description {
+        Description::new::<Id, NL, EL, Ty>(Graph::GraphMap)
+    }
+}
+
+impl<Id, NL, EL, Ty> Describe for TypedStaticGraph<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + TypeTag,
+    EL: Hash + Eq + TypeTag,
+    Ty: GraphType,
+{
+    fn describe() -> Description {
+        Description::new::<Id, NL, EL, Ty>(Graph::StaticGraph)
+    }
+
+    fn check(&self) -> error::Result<()> {
+        self.validate()
+    }
+}
+
+/// The header of a graph file, which is preceded by `MAGIC` and the format version
+/// (`u32`, little endian) and followed by the serialized graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    description: Description,
+    payload_len: u64,
+    // CRC32 (IEEE) of the serialized graph.
+    checksum: u32,
+}
+
+impl Header {
+    pub fn get_description(&self) -> &Description {
+        &self.description
+    }
+
+    /// The size of the serialized graph in bytes.
+    pub fn get_payload_len(&self) -> u64 {
+        self.payload_len
+    }
+
+    pub fn get_checksum(&self) -> u32 {
+        self.checksum
+    }
+}
+
+#[derive(Debug)]
+pub enum FormatError {
+    Io(io::Error),
+    Bincode(bincode::Error),
+    /// The file does not start with `MAGIC`.
+    NotAGraphFile,
+    UnsupportedVersion(u32),
+    /// The file holds a graph other than the requested type.
+    Mismatch {
+        field: &'static str,
+        expected: String,
+        found: String,
+    },
+    /// The serialized graph is corrupted.
+    ChecksumMismatch {
+        expected: u32,
+        found: u32,
+    },
+    /// The loaded graph is inconsistent, as per `Describe::check`.
+    InvalidGraph(String),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FormatError::Io(ref e) => write!(f, "IO error: {}", e),
+            FormatError::Bincode(ref e) => write!(f, "Bincode error: {}", e),
+            FormatError::NotAGraphFile => write!(f, "Not a graph file."),
+            FormatError::UnsupportedVersion(v) => {
+                write!(f, "Unsupported version {} (expect {}).", v, VERSION)
+            }
+            FormatError::Mismatch {
+                field,
+                ref expected,
+                ref found,
+            } => write!(
+                f,
+                "Mismatched {}: expect {}, found {}.",
+                field, expected, found
+            ),
+            FormatError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "Mismatched checksum: expect {:#010x}, found {:#010x}.",
+                expected, found
+            ),
+            FormatError::InvalidGraph(ref message) => write!(f, "Invalid graph: {}", message),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+impl From<io::Error> for FormatError {
+    fn from(error: io::Error) -> Self {
+        FormatError::Io(error)
+    }
+}
+
+impl From<bincode::Error> for FormatError {
+    fn from(error: bincode::Error) -> Self {
+        FormatError::Bincode(error)
+    }
+}
+
+/// Write a graph, together with a header describing it, to `path`.
+pub fn export_graph<G, P>(g: &G, path: P) -> ::std::result::Result<(), FormatError>
+where
+    G: Describe + ser::Serialize,
+    P: AsRef<Path>,
+{
+    let payload = serialize(g, Infinite)?;
+    let header = Header {
+        description: G::describe(),
+        payload_len: payload.len() as u64,
+        checksum: crc32::checksum_ieee(&payload),
+    };
+
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    serialize_into(&mut writer, &header, Infinite)?;
+    writer.write_all(&payload)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Load a graph written by `export_graph`, checking that it is of type `G`, not corrupted
+/// and consistent.
+pub fn import_graph<G, P>(path: P) -> ::std::result::Result<G, FormatError>
+where
+    G: Describe + de::DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let mut reader = BufReader::new(File::open(path)?);
+    let header = _read_header(&mut reader)?;
+
+    let expected = G::describe();
+    let found = header.get_description();
+
+    _check_field(
+        "implementation",
+        &expected.implementation,
+        &found.implementation,
+    )?;
+    _check_field("id size", &expected.id_size, &found.id_size)?;
+    _check_field("directedness", &expected.is_directed, &found.is_directed)?;
+    _check_field(
+        "node label type",
+        &expected.node_label_type,
+        &found.node_label_type,
+    )?;
+    _check_field(
+        "edge label type",
+        &expected.edge_label_type,
+        &found.edge_label_type,
+    )?;
+
+    let mut payload = Vec::new();
+    reader.take(header.payload_len).read_to_end(&mut payload)?;
+
+    if payload.len() as u64 != header.payload_len {
+        return Err(FormatError::Io(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "Expect {} bytes of graph, found {}.",
+                header.payload_len,
+                payload.len()
+            ),
+        )));
+    }
+
+    let checksum = crc32::checksum_ieee(&payload);
+    if checksum != header.checksum {
+        return Err(FormatError::ChecksumMismatch {
+            expected: header.checksum,
+            found: checksum,
+        });
+    }
+
+    let g: G = deserialize(&payload)?;
+    g.check().map_err(|e| FormatError::InvalidGraph(e.to_string()))?;
+
+    Ok(g)
+}
+
+/// Read the header of a graph file without loading the graph.
+pub fn read_header<P: AsRef<Path>>(path: P) -> ::std::result::Result<Header, FormatError> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    _read_header(&mut reader)
+}
+
+fn _read_header<R: Read>(reader: &mut R) -> ::std::result::Result<Header, FormatError> {
+    let mut magic = [0u8; 8];
+    if reader.read_exact(&mut magic).is_err() || magic != MAGIC {
+        return Err(FormatError::NotAGraphFile);
+    }
+
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    let version = u32::from_le_bytes(version);
+
+    if version != VERSION {
+        return Err(FormatError::UnsupportedVersion(version));
+    }
+
+    Ok(deserialize_from(reader, Bounded(MAX_HEADER_SIZE))?)
+}
+
+fn _check_field<T: PartialEq + fmt::Debug>(
+    field: &'static str,
+    expected: &T,
+    found: &T,
+) -> ::std::result::Result<(), FormatError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(FormatError::Mismatch {
+            field,
+            expected: format!("{:?}", expected),
+            found: format!("{:?}", found),
+        })
+    }
+}
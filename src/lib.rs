@@ -1,10 +1,13 @@
 extern crate bincode;
+extern crate crc;
 extern crate csv;
 extern crate indexmap;
 extern crate itertools;
 extern crate rand;
 extern crate regex;
 extern crate serde;
+extern crate serde_json;
+extern crate toml;
 
 #[macro_use]
 extern crate log;
@@ -13,6 +16,7 @@ extern crate log;
 extern crate serde_derive;
 
 pub mod converter;
+pub mod error;
 pub mod generic;
 pub mod graph_gen;
 pub mod graph_impl;
@@ -20,8 +24,11 @@ pub mod io;
 pub mod map;
 pub mod pattern_matching;
 pub mod prelude;
+pub mod set_ops;
 
 pub use graph_impl::{DiGraphMap, GraphMap, UnGraphMap};
 pub use graph_impl::{DiStaticGraph, StaticGraph, UnStaticGraph};
 
+pub use error::GraphError;
+
 pub use converter::{DiStaticGraphConverter, StaticGraphConverter, UnStaticGraphConverter};
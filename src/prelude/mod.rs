@@ -10,7 +10,8 @@ pub use generic::Void;
 pub use generic::{DefaultId, IdType};
 pub use generic::{Directed, Undirected};
 
-pub use generic::{DiGraphTrait, GeneralGraph, GraphLabelTrait, GraphTrait, MutGraphLabelTrait,
-                  MutGraphTrait, UnGraphTrait};
+pub use generic::{DiGraphIterTrait, DiGraphTrait, GeneralGraph, GraphIterTrait, GraphLabelTrait,
+                  GraphTrait, MutGraphLabelTrait, MutGraphTrait, UnGraphTrait};
+pub use generic::{DuplicatePolicy, InsertStats};
 pub use generic::{EdgeTrait, MutEdgeTrait, MutNodeTrait, NodeTrait};
 pub use generic::{MapTrait, MutMapTrait};
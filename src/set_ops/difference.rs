@@ -0,0 +1,50 @@
+use std::cmp::Ordering;
+
+use generic::IdType;
+use set_ops::gallop;
+use set_ops::intersection::{intersect_count, GALLOPING_RATIO};
+
+/// The elements of `a` that are not in `b`. Gallops through `b` if it is much larger than
+/// `a`, and merges the two otherwise.
+pub fn difference<Id: IdType>(a: &[Id], b: &[Id]) -> Vec<Id> {
+    let mut result = Vec::with_capacity(a.len());
+
+    if a.len() * GALLOPING_RATIO <= b.len() {
+        let mut start = 0;
+
+        for &x in a {
+            start = gallop(b, start, &x);
+
+            if start == b.len() || b[start] != x {
+                result.push(x);
+            }
+        }
+
+        return result;
+    }
+
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                result.push(a[i]);
+                i += 1;
+            }
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    result.extend_from_slice(&a[i..]);
+
+    result
+}
+
+/// The number of elements of `a` that are not in `b`.
+pub fn difference_count<Id: IdType>(a: &[Id], b: &[Id]) -> usize {
+    a.len() - intersect_count(a, b)
+}
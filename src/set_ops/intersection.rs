@@ -0,0 +1,220 @@
+use std::cmp::Ordering;
+use std::mem;
+
+use generic::IdType;
+use set_ops::gallop;
+
+/// The hybrid kernels gallop through the larger set if it is at least this many times larger
+/// than the smaller one, and merge the two otherwise.
+pub const GALLOPING_RATIO: usize = 32;
+
+// The number of elements of each set compared at once by the block kernels.
+const BLOCK: usize = 4;
+
+/// Intersect `a` and `b` by merging them, in `O(|a| + |b|)` time.
+pub fn intersect_merge<Id: IdType>(a: &[Id], b: &[Id]) -> Vec<Id> {
+    let mut result = Vec::with_capacity(a.len().min(b.len()));
+    merge_with(a, b, |x| result.push(x));
+
+    result
+}
+
+/// Intersect `a` and `b` by galloping through the larger one for every element of the
+/// smaller one, in `O(|small| * log(|large| / |small|))` time.
+pub fn intersect_galloping<Id: IdType>(a: &[Id], b: &[Id]) -> Vec<Id> {
+    let mut result = Vec::with_capacity(a.len().min(b.len()));
+    gallop_with(a, b, |x| result.push(x));
+
+    result
+}
+
+/// Intersect `a` and `b` by merging blocks of `4` elements, comparing all pairs in a block
+/// without branching, which the compiler may vectorize.
+pub fn intersect_block<Id: IdType>(a: &[Id], b: &[Id]) -> Vec<Id> {
+    let mut result = Vec::with_capacity(a.len().min(b.len()));
+    block_with(a, b, |x| result.push(x));
+
+    result
+}
+
+/// Intersect `a` and `b`, choosing the kernel by their sizes.
+pub fn intersect<Id: IdType>(a: &[Id], b: &[Id]) -> Vec<Id> {
+    let mut result = Vec::new();
+    intersect_into(a, b, &mut result);
+
+    result
+}
+
+/// Intersect `a` and `b` into `result`, which is cleared first, so that its buffer can be
+/// reused across calls.
+pub fn intersect_into<Id: IdType>(a: &[Id], b: &[Id], result: &mut Vec<Id>) {
+    result.clear();
+    result.reserve(a.len().min(b.len()));
+    hybrid_with(a, b, |x| result.push(x));
+}
+
+/// The size of the intersection of `a` and `b`, by merging.
+pub fn intersect_count_merge<Id: IdType>(a: &[Id], b: &[Id]) -> usize {
+    let mut count = 0;
+    merge_with(a, b, |_| count += 1);
+
+    count
+}
+
+/// The size of the intersection of `a` and `b`, by galloping.
+pub fn intersect_count_galloping<Id: IdType>(a: &[Id], b: &[Id]) -> usize {
+    let mut count = 0;
+    gallop_with(a, b, |_| count += 1);
+
+    count
+}
+
+/// The size of the intersection of `a` and `b`, by merging blocks.
+pub fn intersect_count_block<Id: IdType>(a: &[Id], b: &[Id]) -> usize {
+    let (mut i, mut j) = (0, 0);
+    let mut count = 0;
+
+    while i + BLOCK <= a.len() && j + BLOCK <= b.len() {
+        let (x, y) = (&a[i..i + BLOCK], &b[j..j + BLOCK]);
+
+        for u in x {
+            for v in y {
+                count += (u == v) as usize;
+            }
+        }
+
+        advance(x, y, &mut i, &mut j);
+    }
+
+    count + intersect_count_merge(&a[i..], &b[j..])
+}
+
+/// The size of the intersection of `a` and `b`, choosing the kernel by their sizes.
+pub fn intersect_count<Id: IdType>(a: &[Id], b: &[Id]) -> usize {
+    let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if small.len() * GALLOPING_RATIO <= large.len() {
+        intersect_count_galloping(small, large)
+    } else {
+        intersect_count_block(small, large)
+    }
+}
+
+/// Intersect all of `sets`, from the smallest to the largest, which is empty if `sets` is.
+pub fn intersect_all<Id: IdType>(sets: &[&[Id]]) -> Vec<Id> {
+    let sets = sorted_by_len(sets);
+    let mut result = match sets.first() {
+        Some(set) => set.to_vec(),
+        None => return Vec::new(),
+    };
+    let mut buffer = Vec::with_capacity(result.len());
+
+    for set in sets[1..].iter() {
+        if result.is_empty() {
+            break;
+        }
+
+        intersect_into(&result, set, &mut buffer);
+        mem::swap(&mut result, &mut buffer);
+    }
+
+    result
+}
+
+/// The size of the intersection of all of `sets`, which is `0` if `sets` is empty. The
+/// largest set is only counted against, rather than intersected with.
+pub fn intersect_all_count<Id: IdType>(sets: &[&[Id]]) -> usize {
+    let mut sets = sorted_by_len(sets);
+
+    match sets.pop() {
+        None => 0,
+        Some(largest) if sets.is_empty() => largest.len(),
+        Some(largest) => intersect_count(&intersect_all(&sets), largest),
+    }
+}
+
+fn sorted_by_len<'a, Id>(sets: &[&'a [Id]]) -> Vec<&'a [Id]> {
+    let mut sets = sets.to_vec();
+    sets.sort_by_key(|set| set.len());
+
+    sets
+}
+
+#[inline]
+fn merge_with<Id: IdType, F: FnMut(Id)>(a: &[Id], b: &[Id], mut f: F) {
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                f(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+}
+
+#[inline]
+fn gallop_with<Id: IdType, F: FnMut(Id)>(a: &[Id], b: &[Id], mut f: F) {
+    let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let mut start = 0;
+
+    for &x in small {
+        start = gallop(large, start, &x);
+
+        if start == large.len() {
+            break;
+        }
+
+        if large[start] == x {
+            f(x);
+            start += 1;
+        }
+    }
+}
+
+#[inline]
+fn block_with<Id: IdType, F: FnMut(Id)>(a: &[Id], b: &[Id], mut f: F) {
+    let (mut i, mut j) = (0, 0);
+
+    while i + BLOCK <= a.len() && j + BLOCK <= b.len() {
+        let (x, y) = (&a[i..i + BLOCK], &b[j..j + BLOCK]);
+
+        for &u in x {
+            if y.iter().fold(false, |found, &v| found | (u == v)) {
+                f(u);
+            }
+        }
+
+        advance(x, y, &mut i, &mut j);
+    }
+
+    merge_with(&a[i..], &b[j..], f);
+}
+
+// Skip the block(s) with the smaller maximum, whose elements can match no later ones.
+#[inline]
+fn advance<Id: IdType>(x: &[Id], y: &[Id], i: &mut usize, j: &mut usize) {
+    let (x_max, y_max) = (x[BLOCK - 1], y[BLOCK - 1]);
+
+    if x_max <= y_max {
+        *i += BLOCK;
+    }
+    if y_max <= x_max {
+        *j += BLOCK;
+    }
+}
+
+#[inline]
+fn hybrid_with<Id: IdType, F: FnMut(Id)>(a: &[Id], b: &[Id], f: F) {
+    let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if small.len() * GALLOPING_RATIO <= large.len() {
+        gallop_with(small, large, f);
+    } else {
+        block_with(small, large, f);
+    }
+}
@@ -0,0 +1,35 @@
+//! Kernels of set operations over sorted slices of ids, e.g., the neighbors of a node in
+//! `EdgeVec`. Every input must be sorted in ascending order without duplicates, and so is
+//! every output.
+
+pub mod difference;
+pub mod intersection;
+pub mod union;
+
+pub use set_ops::difference::{difference, difference_count};
+pub use set_ops::intersection::{intersect, intersect_all, intersect_all_count, intersect_block,
+                                intersect_count, intersect_count_block,
+                                intersect_count_galloping, intersect_count_merge,
+                                intersect_galloping, intersect_into, intersect_merge};
+pub use set_ops::intersection::GALLOPING_RATIO;
+pub use set_ops::union::{union, union_count};
+
+/// The first index `i >= from` with `slice[i] >= target`, or `slice.len()` if there is none.
+/// The steps double from `from` before a binary search, so that it takes `O(log d)` time,
+/// where `d` is the distance from `from` to the result.
+#[inline]
+fn gallop<Id: Ord>(slice: &[Id], from: usize, target: &Id) -> usize {
+    let mut low = from;
+    let mut high = from;
+    let mut step = 1;
+
+    while high < slice.len() && slice[high] < *target {
+        low = high + 1;
+        high += step;
+        step <<= 1;
+    }
+
+    let high = ::std::cmp::min(high, slice.len());
+
+    low + slice[low..high].partition_point(|x| x < target)
+}
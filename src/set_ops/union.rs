@@ -0,0 +1,38 @@
+use std::cmp::Ordering;
+
+use generic::IdType;
+use set_ops::intersection::intersect_count;
+
+/// The elements in either `a` or `b`.
+pub fn union<Id: IdType>(a: &[Id], b: &[Id]) -> Vec<Id> {
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                result.push(a[i]);
+                i += 1;
+            }
+            Ordering::Greater => {
+                result.push(b[j]);
+                j += 1;
+            }
+            Ordering::Equal => {
+                result.push(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    result.extend_from_slice(&a[i..]);
+    result.extend_from_slice(&b[j..]);
+
+    result
+}
+
+/// The number of elements in either `a` or `b`.
+pub fn union_count<Id: IdType>(a: &[Id], b: &[Id]) -> usize {
+    a.len() + b.len() - intersect_count(a, b)
+}
@@ -2,6 +2,9 @@ extern crate rust_graph;
 
 use rust_graph::prelude::*;
 
+use rust_graph::converter::NodeOrder;
+use rust_graph::graph_gen::random_gnm_graph;
+use rust_graph::{DiGraphMap, UnGraphMap};
 use rust_graph::{DiStaticGraphConverter, UnStaticGraphConverter};
 
 #[test]
@@ -98,3 +101,190 @@ fn test_directed() {
 
     assert_eq!(edges, vec![(1, 0), (2, 0), (2, 3), (3, 0), (3, 1), (3, 2)]);
 }
+
+#[test]
+fn test_directed_in_edges() {
+    let mut g = rust_graph::DiGraphMap::<&str>::new();
+
+    g.add_edge(1, 0, Some("a"));
+    g.add_edge(2, 0, Some("b"));
+    g.add_edge(2, 3, None);
+    g.add_edge(3, 1, Some("b"));
+    g.add_edge(3, 2, Some("a"));
+
+    for &reorder in &[false, true] {
+        let g = DiStaticGraphConverter::new(g.clone(), reorder, reorder).convert();
+
+        assert!(g.validate().is_ok());
+
+        for t in g.node_indices() {
+            let in_neighbors: Vec<_> = g.in_neighbors_with_labels_iter(t).collect();
+            let expected: Vec<_> = g
+                .in_neighbors_iter(t)
+                .map(|s| (s, g.get_edge(s, t).get_label_id()))
+                .collect();
+
+            assert_eq!(in_neighbors, expected);
+        }
+    }
+}
+
+#[test]
+fn test_partly_labeled() {
+    let mut map = rust_graph::DiGraphMap::<&str>::new();
+
+    map.add_edge(0, 1, Some("a"));
+    map.add_edge(0, 2, None);
+    map.add_edge(1, 2, Some("b"));
+    map.add_edge(2, 0, None);
+    map.add_edge(2, 1, Some("a"));
+
+    let g = DiStaticGraphConverter::new(map.clone(), false, false).convert();
+
+    let static_label =
+        |id: Option<DefaultId>| id.and_then(|l| g.get_edge_label_map().get_item(l.id()));
+
+    for v in map.node_indices() {
+        let expected: Vec<_> = map
+            .neighbors_iter(v)
+            .map(|t| (t, map.get_edge_label(v, t)))
+            .collect();
+        let neighbors: Vec<_> = g
+            .neighbors_iter(v)
+            .map(|t| (t, g.get_edge_label(v, t)))
+            .collect();
+        assert_eq!(neighbors, expected);
+
+        let expected: Vec<_> = map
+            .in_neighbors_iter(v)
+            .map(|s| (s, map.get_edge_label(s, v)))
+            .collect();
+        let in_neighbors: Vec<_> = g
+            .in_neighbors_with_labels(v)
+            .map(|(s, l)| (s, static_label(l)))
+            .collect();
+        assert_eq!(in_neighbors, expected);
+    }
+
+    // The unlabeled edges have no label id, rather than the `Id::max_value()` sentinel.
+    assert_eq!(g.get_edge(0, 2).get_label_id(), None);
+    assert_eq!(g.get_edge(2, 0).get_label_id(), None);
+}
+
+#[test]
+fn test_try_new() {
+    let mut g = rust_graph::UnGraphMap::<&str>::new();
+
+    g.add_edge(0, 1, None);
+    g.add_edge(1, 5, None);
+
+    assert!(UnStaticGraphConverter::try_new(g.clone(), false, false).is_err());
+
+    let converter = UnStaticGraphConverter::try_new(g, true, false).unwrap();
+    assert_eq!(converter.convert().node_count(), 3);
+}
+
+#[test]
+fn test_node_orders() {
+    let orders = [
+        NodeOrder::DegreeAscending,
+        NodeOrder::DegreeDescending,
+        NodeOrder::Bfs,
+        NodeOrder::ReverseCuthillMcKee,
+        NodeOrder::Gorder { window: 5 },
+        NodeOrder::LabelGrouped,
+        NodeOrder::Random(42),
+    ];
+
+    let un_graph: UnGraphMap<&str, &str> =
+        random_gnm_graph(50, 150, vec!["a", "b", "c"], vec!["x", "y"]);
+    let di_graph: DiGraphMap<&str, &str> =
+        random_gnm_graph(50, 150, vec!["a", "b", "c"], vec!["x", "y"]);
+
+    for &order in orders.iter() {
+        let converter = UnStaticGraphConverter::with_node_order(un_graph.clone(), order, true);
+        assert_eq!(converter.get_node_order(), order);
+
+        let g = converter.convert();
+        assert_eq!(g.node_count(), un_graph.node_count());
+        assert_eq!(g.edge_count(), un_graph.edge_count());
+
+        for v in g.node_indices() {
+            let original = converter.get_original_node_id(v);
+            assert_eq!(converter.find_new_node_id(original), v);
+            assert_eq!(g.get_node_label(v), un_graph.get_node_label(original));
+        }
+
+        for (s, t) in g.edge_indices() {
+            let (original_s, original_t) = (
+                converter.get_original_node_id(s),
+                converter.get_original_node_id(t),
+            );
+            assert_eq!(
+                g.get_edge_label(s, t),
+                un_graph.get_edge_label(original_s, original_t)
+            );
+        }
+
+        let converter = DiStaticGraphConverter::with_node_order(di_graph.clone(), order, false);
+        let g = converter.convert();
+        assert!(g.validate().is_ok());
+
+        for (s, t) in g.edge_indices() {
+            assert!(di_graph.has_edge(
+                converter.get_original_node_id(s),
+                converter.get_original_node_id(t)
+            ));
+        }
+    }
+
+    let g = UnStaticGraphConverter::with_node_order(
+        un_graph.clone(),
+        NodeOrder::DegreeDescending,
+        false,
+    )
+    .convert();
+    let degrees: Vec<_> = g.node_indices().map(|v| g.degree(v)).collect();
+    assert!(degrees.windows(2).all(|pair| pair[0] >= pair[1]));
+
+    let g =
+        UnStaticGraphConverter::with_node_order(un_graph.clone(), NodeOrder::LabelGrouped, true)
+            .convert();
+    let labels: Vec<_> = g.node_indices().map(|v| g.get_node_label(v)).collect();
+    let mut groups = labels.clone();
+    groups.dedup();
+    assert_eq!(groups.len(), 3);
+
+    let random =
+        UnStaticGraphConverter::with_node_order(un_graph.clone(), NodeOrder::Random(7), false);
+    let same_random =
+        UnStaticGraphConverter::with_node_order(un_graph, NodeOrder::Random(7), false);
+    assert_eq!(random.get_node_id_map(), same_random.get_node_id_map());
+}
+
+#[test]
+fn test_reverse_cuthill_mckee() {
+    // A path over scrambled ids, which is a band of width 1 once reordered.
+    let mut g = UnGraphMap::<Void>::new();
+    let path: Vec<_> = (0..20).map(|i| (i * 7) % 20).collect();
+
+    for pair in path.windows(2) {
+        g.add_edge(pair[0], pair[1], None);
+    }
+
+    let g =
+        UnStaticGraphConverter::with_node_order(g, NodeOrder::ReverseCuthillMcKee, false).convert();
+
+    for (s, t) in g.edge_indices() {
+        assert_eq!(t - s, 1);
+    }
+
+    let mut g = UnGraphMap::<Void>::new();
+    g.add_edge(0, 1, None);
+    g.add_edge(1, 5, None);
+
+    assert!(
+        UnStaticGraphConverter::try_with_node_order(g.clone(), NodeOrder::Original, false).is_err()
+    );
+    assert!(UnStaticGraphConverter::try_with_node_order(g, NodeOrder::Bfs, false).is_ok());
+}
@@ -2,9 +2,10 @@ extern crate rust_graph;
 
 use rust_graph::prelude::*;
 
-use rust_graph::graph_gen::{complete_graph_unlabeled, empty_graph_unlabeled};
-use rust_graph::graph_gen::{random_gnm_graph_unlabeled, random_gnp_graph_unlabeled};
-use rust_graph::graph_impl::{DiGraphMap, UnGraphMap};
+use rust_graph::graph_gen::*;
+
+use rust_graph::graph_impl::{DiGraphMap, DiStaticGraph, UnGraphMap, UnStaticGraph};
+use rust_graph::graph_impl::{TypedDiTemporalGraph, UnTemporalGraph};
 
 #[test]
 fn test_random_gnp_graph() {
@@ -30,3 +31,763 @@ fn test_random_gnm_graph() {
     assert_eq!(g2.node_count(), num_of_nodes);
     assert_eq!(g2.edge_count(), num_of_edges);
 }
+
+#[test]
+fn test_barabasi_albert_graph() {
+    let num_of_nodes = 100;
+    let m = 3;
+
+    let g: UnGraphMap<u8> = barabasi_albert_graph(num_of_nodes, m, Vec::new(), Vec::new());
+    assert_eq!(g.node_count(), num_of_nodes);
+    assert_eq!(g.edge_count(), (num_of_nodes - m) * m);
+    for i in m..num_of_nodes {
+        assert!(g.degree(i as DefaultId) >= m);
+    }
+
+    let node_labels = vec!["a".to_owned(), "b".to_owned()];
+    let edge_labels = vec![1, 2, 3];
+
+    let g: DiGraphMap<String, u32> =
+        barabasi_albert_graph(num_of_nodes, m, node_labels.clone(), edge_labels.clone());
+    assert_eq!(g.edge_count(), (num_of_nodes - m) * m);
+    assert!(g.node_labels().all(|l| node_labels.contains(l)));
+    assert!(g.edge_labels().all(|l| edge_labels.contains(l)));
+}
+
+#[test]
+fn test_extended_barabasi_albert_graph() {
+    let num_of_nodes = 100;
+    let m = 2;
+
+    let g: UnGraphMap<u8> =
+        extended_barabasi_albert_graph(num_of_nodes, m, 0f32, 0f32, Vec::new(), Vec::new());
+    assert_eq!(g.node_count(), num_of_nodes);
+    assert_eq!(g.edge_count(), (num_of_nodes - m) * m);
+
+    let g: UnGraphMap<u8> =
+        extended_barabasi_albert_graph(num_of_nodes, m, 0.3f32, 0.3f32, Vec::new(), Vec::new());
+    assert_eq!(g.node_count(), num_of_nodes);
+    assert!(g.edge_count() >= (num_of_nodes - m) * m);
+}
+
+#[test]
+fn test_holme_kim_graph() {
+    let num_of_nodes = 100;
+    let m = 3;
+
+    for &p in &[0f32, 0.5f32, 1f32] {
+        let g: UnGraphMap<u8> = holme_kim_graph(num_of_nodes, m, p, Vec::new(), Vec::new());
+        assert_eq!(g.node_count(), num_of_nodes);
+        assert!(g.edge_count() <= (num_of_nodes - m) * m);
+        for i in m..num_of_nodes {
+            assert!(g.degree(i as DefaultId) >= 1);
+        }
+    }
+}
+
+#[test]
+fn test_rmat_graph() {
+    let scale = 10;
+    let edge_factor = 8;
+
+    let g: UnStaticGraph<Void> = graph500_graph(scale, edge_factor);
+    assert_eq!(g.node_count(), 1 << scale);
+    assert!(g.edge_count() <= edge_factor << scale);
+    for (s, t) in g.edge_indices() {
+        assert_ne!(s, t);
+        assert!(g.has_edge(t, s));
+    }
+
+    let generator = RMatGenerator::new(scale, edge_factor)
+        .with_probabilities(0.45, 0.15, 0.15, 0.25)
+        .with_noise(0.1)
+        .with_deduplication(false)
+        .with_self_loops(true)
+        .with_threads(4);
+
+    let g: DiStaticGraph<Void> = generator.generate();
+    assert_eq!(g.node_count(), 1 << scale);
+    assert_eq!(g.edge_count(), edge_factor << scale);
+
+    // Every edge is in the quadrant of either `b` or `c` at every level.
+    let g: DiStaticGraph<Void> = RMatGenerator::new(scale, edge_factor)
+        .with_probabilities(0f32, 0.5, 0.5, 0f32)
+        .generate();
+    assert!(g.edge_count() > 0);
+    for (s, t) in g.edge_indices() {
+        assert_eq!(s ^ t, (1 << scale) - 1);
+    }
+}
+
+#[test]
+fn test_small_world_graph() {
+    let num_of_nodes = 100;
+    let k = 4;
+
+    let lattice: UnGraphMap<u8> =
+        watts_strogatz_graph(num_of_nodes, k, 0f32, Vec::new(), Vec::new());
+    assert_eq!(lattice.edge_count(), num_of_nodes * k / 2);
+    for i in 0..num_of_nodes as DefaultId {
+        assert_eq!(lattice.degree(i), k);
+        assert!(lattice.has_edge(i, (i + 1) % num_of_nodes as DefaultId));
+        assert!(lattice.has_edge(i, (i + 2) % num_of_nodes as DefaultId));
+    }
+
+    let g: UnGraphMap<u8> = watts_strogatz_graph(num_of_nodes, k, 0.5f32, Vec::new(), Vec::new());
+    assert_eq!(g.node_count(), num_of_nodes);
+    assert_eq!(g.edge_count(), num_of_nodes * k / 2);
+    assert_ne!(g, lattice);
+
+    let g: UnGraphMap<u8> = newman_watts_graph(num_of_nodes, k, 0f32, Vec::new(), Vec::new());
+    assert_eq!(g, lattice);
+
+    let g: UnGraphMap<u8> = newman_watts_graph(num_of_nodes, k, 0.5f32, Vec::new(), Vec::new());
+    assert!(g.edge_count() > num_of_nodes * k / 2);
+    for (s, t) in lattice.edge_indices() {
+        assert!(g.has_edge(s, t));
+    }
+}
+
+#[test]
+fn test_random_regular_graph() {
+    let num_of_nodes = 100;
+
+    for &d in &[1, 3, 10] {
+        let g: UnGraphMap<u8> = random_regular_graph(num_of_nodes, d, Vec::new(), Vec::new());
+        assert_eq!(g.edge_count(), num_of_nodes * d / 2);
+        for i in 0..num_of_nodes as DefaultId {
+            assert_eq!(g.degree(i), d);
+            assert!(!g.has_edge(i, i));
+        }
+    }
+}
+
+#[test]
+fn test_configuration_model_graph() {
+    let degrees: Vec<usize> = (0..100).map(|i| 1 + i % 5).collect();
+
+    let g: UnGraphMap<u8> = configuration_model_graph(&degrees, true, Vec::new(), Vec::new());
+    assert_eq!(g.node_count(), degrees.len());
+    for (i, &d) in degrees.iter().enumerate() {
+        assert!(g.degree(i as DefaultId) <= d);
+        assert!(!g.has_edge(i as DefaultId, i as DefaultId));
+    }
+
+    let g: UnGraphMap<u8> = configuration_model_graph(&[2, 2, 2], false, Vec::new(), Vec::new());
+    assert!(g.edge_count() <= 3);
+}
+
+#[test]
+fn test_stochastic_block_model_graph() {
+    let sizes = [10, 20, 30];
+    let probabilities = vec![
+        vec![1f32, 0f32, 0f32],
+        vec![0f32, 1f32, 1f32],
+        vec![0f32, 1f32, 0f32],
+    ];
+
+    let (g, blocks): (UnGraphMap<u8>, _) =
+        stochastic_block_model_graph(&sizes, &probabilities, Vec::new(), Vec::new());
+    assert_eq!(g.node_count(), 60);
+    assert_eq!(blocks.len(), 60);
+    assert_eq!(g.edge_count(), 45 + 190 + 20 * 30);
+    for (s, t) in g.edge_indices() {
+        assert!(probabilities[blocks[s as usize]][blocks[t as usize]] > 0f32);
+    }
+
+    let node_labels = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+    let (g, blocks): (DiGraphMap<String>, _) =
+        planted_partition_graph(3, 10, 1f32, 0f32, node_labels.clone(), Vec::new());
+    assert_eq!(g.edge_count(), 3 * 10 * 9);
+    for i in 0..30 {
+        assert_eq!(blocks[i], i / 10);
+        assert_eq!(g.get_node_label(i as DefaultId), Some(&node_labels[i / 10]));
+    }
+}
+
+#[test]
+fn test_lfr_benchmark_graph() {
+    let params = LfrParams {
+        n: 250,
+        tau1: 3f64,
+        tau2: 1.5f64,
+        mu: 0.1f64,
+        average_degree: 5f64,
+        max_degree: 20,
+        min_community: 20,
+        max_community: 50,
+    };
+
+    let (g, communities): (UnGraphMap<u8>, _) =
+        lfr_benchmark_graph(&params, Vec::new(), Vec::new());
+    assert_eq!(g.node_count(), params.n);
+    assert_eq!(communities.len(), params.n);
+
+    let mut sizes = vec![0; communities.iter().max().unwrap() + 1];
+    for &c in &communities {
+        sizes[c] += 1;
+    }
+    assert!(sizes.iter().all(|&s| s > 0 && s <= params.max_community));
+
+    let internal = g
+        .edge_indices()
+        .filter(|&(s, t)| communities[s as usize] == communities[t as usize])
+        .count();
+    assert!(internal * 2 > g.edge_count());
+
+    let rng = &mut seeded_rng(42);
+    let invalid = [
+        LfrParams {
+            max_degree: 0,
+            ..params.clone()
+        },
+        LfrParams {
+            max_degree: params.n,
+            ..params.clone()
+        },
+        LfrParams {
+            average_degree: 0.5f64,
+            ..params.clone()
+        },
+        LfrParams {
+            average_degree: 21f64,
+            ..params.clone()
+        },
+        LfrParams {
+            average_degree: ::std::f64::NAN,
+            ..params.clone()
+        },
+        LfrParams {
+            average_degree: ::std::f64::INFINITY,
+            ..params.clone()
+        },
+        LfrParams {
+            tau1: ::std::f64::NAN,
+            ..params.clone()
+        },
+    ];
+    for params in &invalid {
+        let result: Result<(UnGraphMap<u8>, _), _> =
+            try_lfr_benchmark_graph_with_rng(rng, params, Vec::new(), Vec::new());
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn test_seeded_generation() {
+    let node_labels = vec!["a".to_owned(), "b".to_owned()];
+    let edge_labels = vec![1, 2, 3];
+
+    macro_rules! assert_reproducible {
+        ($ty:ty, $gen:expr) => {{
+            let g1: $ty = $gen(&mut seeded_rng(42));
+            let g2: $ty = $gen(&mut seeded_rng(42));
+            assert_eq!(g1, g2);
+        }};
+    }
+
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| random_gnp_graph_with_rng(
+        rng,
+        50,
+        0.1f32,
+        node_labels.clone(),
+        edge_labels.clone()
+    ));
+    assert_reproducible!(DiGraphMap<String, u32>, |rng| random_gnm_graph_with_rng(
+        rng,
+        50,
+        200,
+        node_labels.clone(),
+        edge_labels.clone()
+    ));
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| barabasi_albert_graph_with_rng(
+        rng,
+        50,
+        3,
+        node_labels.clone(),
+        edge_labels.clone()
+    ));
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| {
+        extended_barabasi_albert_graph_with_rng(
+            rng,
+            50,
+            2,
+            0.2f32,
+            0.2f32,
+            node_labels.clone(),
+            edge_labels.clone(),
+        )
+    });
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| holme_kim_graph_with_rng(
+        rng,
+        50,
+        3,
+        0.5f32,
+        node_labels.clone(),
+        edge_labels.clone()
+    ));
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| watts_strogatz_graph_with_rng(
+        rng,
+        50,
+        4,
+        0.3f32,
+        node_labels.clone(),
+        edge_labels.clone()
+    ));
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| newman_watts_graph_with_rng(
+        rng,
+        50,
+        4,
+        0.3f32,
+        node_labels.clone(),
+        edge_labels.clone()
+    ));
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| random_regular_graph_with_rng(
+        rng,
+        50,
+        4,
+        node_labels.clone(),
+        edge_labels.clone()
+    ));
+    assert_reproducible!(UnGraphMap<String, u32>, |rng| {
+        configuration_model_graph_with_rng(
+            rng,
+            &[3; 50],
+            true,
+            node_labels.clone(),
+            edge_labels.clone(),
+        )
+    });
+    assert_reproducible!((UnGraphMap<String, u32>, Vec<usize>), |rng| {
+        planted_partition_graph_with_rng(
+            rng,
+            5,
+            10,
+            0.5f32,
+            0.05f32,
+            node_labels.clone(),
+            edge_labels.clone(),
+        )
+    });
+
+    let params = LfrParams {
+        n: 100,
+        tau1: 3f64,
+        tau2: 1.5f64,
+        mu: 0.2f64,
+        average_degree: 5f64,
+        max_degree: 15,
+        min_community: 10,
+        max_community: 30,
+    };
+    assert_reproducible!((UnGraphMap<String, u32>, Vec<usize>), |rng| {
+        lfr_benchmark_graph_with_rng(rng, &params, node_labels.clone(), edge_labels.clone())
+    });
+
+    let generator = RMatGenerator::graph500(8, 4).with_threads(3);
+    let g1: DiStaticGraph<Void> = generator.generate_with_rng(&mut seeded_rng(42));
+    let g2: DiStaticGraph<Void> = generator.generate_with_rng(&mut seeded_rng(42));
+    assert_eq!(
+        g1.edge_indices().collect::<Vec<_>>(),
+        g2.edge_indices().collect::<Vec<_>>()
+    );
+
+    let g1: UnGraphMap<u8> = random_gnm_graph_with_rng(&mut seeded_rng(1), 50, 200, vec![], vec![]);
+    let g2: UnGraphMap<u8> = random_gnm_graph_with_rng(&mut seeded_rng(2), 50, 200, vec![], vec![]);
+    assert_ne!(g1, g2);
+}
+
+#[test]
+fn test_structured_graphs() {
+    let path: UnGraphMap<u8> = path_graph(5, Vec::new(), Vec::new());
+    assert_eq!(path.edge_count(), 4);
+    assert_eq!(path.degree(0), 1);
+    assert_eq!(path.degree(2), 2);
+
+    let cycle: UnGraphMap<u8> = cycle_graph(5, Vec::new(), Vec::new());
+    assert_eq!(cycle.edge_count(), 5);
+    assert!(cycle.node_indices().all(|i| cycle.degree(i) == 2));
+
+    let directed_cycle: DiGraphMap<u8> = cycle_graph(5, Vec::new(), Vec::new());
+    assert!(directed_cycle.has_edge(4, 0));
+    assert!(!directed_cycle.has_edge(0, 4));
+
+    let star: UnGraphMap<u8> = star_graph(5, Vec::new(), Vec::new());
+    assert_eq!(star.node_count(), 6);
+    assert_eq!(star.degree(0), 5);
+
+    let wheel: UnGraphMap<u8> = wheel_graph(6, Vec::new(), Vec::new());
+    assert_eq!(wheel.edge_count(), 10);
+    assert_eq!(wheel.degree(0), 5);
+    assert!((1..6).all(|i| wheel.degree(i) == 3));
+
+    let grid: UnGraphMap<u8> = grid_graph(&[3, 4], Vec::new(), Vec::new());
+    assert_eq!(grid.node_count(), 12);
+    assert_eq!(grid.edge_count(), 3 * 3 + 2 * 4);
+    assert!(grid.has_edge(0, 1) && grid.has_edge(0, 4) && !grid.has_edge(3, 4));
+
+    let grid: UnGraphMap<u8> = grid_graph(&[2, 3, 4], Vec::new(), Vec::new());
+    assert_eq!(grid.edge_count(), 3 * 4 + 2 * 2 * 4 + 2 * 3 * 3);
+
+    let torus: UnGraphMap<u8> = torus_graph(&[3, 4], Vec::new(), Vec::new());
+    assert_eq!(torus.edge_count(), 24);
+    assert!(torus.node_indices().all(|i| torus.degree(i) == 4));
+
+    let torus: UnGraphMap<u8> = torus_graph(&[3, 3, 3], Vec::new(), Vec::new());
+    assert!(torus.node_indices().all(|i| torus.degree(i) == 6));
+
+    let bipartite: UnGraphMap<u8> = complete_bipartite_graph(3, 4, Vec::new(), Vec::new());
+    assert_eq!(bipartite.edge_count(), 12);
+    assert!(!bipartite.has_edge(0, 1) && bipartite.has_edge(0, 3));
+
+    let tree: UnGraphMap<u8> = balanced_tree_graph(2, 3, Vec::new(), Vec::new());
+    assert_eq!(tree.node_count(), 15);
+    assert_eq!(tree.edge_count(), 14);
+    assert!(tree.has_edge(0, 2) && tree.has_edge(2, 6));
+
+    let hypercube: UnGraphMap<u8> = hypercube_graph(4, Vec::new(), Vec::new());
+    assert_eq!(hypercube.node_count(), 16);
+    assert_eq!(hypercube.edge_count(), 32);
+    assert!(hypercube.node_indices().all(|i| hypercube.degree(i) == 4));
+
+    let petersen: UnGraphMap<u8> = petersen_graph(Vec::new(), Vec::new());
+    assert_eq!(petersen.node_count(), 10);
+    assert_eq!(petersen.edge_count(), 15);
+    assert!(petersen.node_indices().all(|i| petersen.degree(i) == 3));
+
+    let lollipop: UnGraphMap<u8> = lollipop_graph(4, 3, Vec::new(), Vec::new());
+    assert_eq!(lollipop.node_count(), 7);
+    assert_eq!(lollipop.edge_count(), 6 + 3);
+    assert!(lollipop.has_edge(3, 4) && lollipop.has_edge(5, 6));
+
+    let node_labels = vec!["a".to_owned()];
+    let labeled: UnGraphMap<String> = petersen_graph(node_labels.clone(), Vec::new());
+    assert!(labeled.node_labels().all(|l| l == "a"));
+}
+
+#[test]
+fn test_sparse_random_graph() {
+    let num_of_nodes = 100_000;
+    let average_degree = 4f32;
+    let p = average_degree / num_of_nodes as f32;
+
+    let g: UnGraphMap<Void> = random_gnp_graph_unlabeled(num_of_nodes, p);
+    let expected = average_degree as usize * num_of_nodes / 2;
+    assert!(g.edge_count() > expected * 9 / 10 && g.edge_count() < expected * 11 / 10);
+
+    let g: DiGraphMap<Void> = random_gnp_graph_unlabeled(1000, 0.01f32);
+    assert!(g.edge_indices().all(|(s, t)| s != t));
+    let expected = 1000 * 999 / 100;
+    assert!(g.edge_count() > expected * 8 / 10 && g.edge_count() < expected * 12 / 10);
+
+    let g: UnGraphMap<Void> = random_gnm_graph_unlabeled(num_of_nodes, 200_000);
+    assert_eq!(g.edge_count(), 200_000);
+
+    let dense: DiGraphMap<Void> = random_gnm_graph_unlabeled(30, 800);
+    assert_eq!(dense.edge_count(), 800);
+    assert!(dense.edge_indices().all(|(s, t)| s != t));
+}
+
+#[test]
+fn test_label_distribution() {
+    let node_labels: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+    let edge_labels = vec![1, 2, 3];
+
+    let mut g: UnGraphMap<String, u32> =
+        random_gnm_graph(2000, 4000, node_labels.clone(), edge_labels.clone());
+
+    assign_labels_with_rng(
+        &mut seeded_rng(7),
+        &mut g,
+        &LabelDistribution::Zipf(2f64),
+        &LabelDistribution::Weighted(vec![0f64, 1f64, 3f64]),
+    );
+
+    let node_counter = g.get_node_label_counter();
+    assert!(node_counter[&node_labels[0]] > node_counter[&node_labels[1]]);
+    assert!(node_counter[&node_labels[1]] > node_counter[&node_labels[4]]);
+
+    let edge_counter = g.get_edge_label_counter();
+    assert!(!edge_counter.contains_key(&1));
+    assert!(edge_counter[&3] > edge_counter[&2]);
+
+    let mut star: UnGraphMap<String, u32> = star_graph(9, node_labels.clone(), edge_labels.clone());
+    assign_labels(
+        &mut star,
+        &LabelDistribution::DegreeCorrelated,
+        &LabelDistribution::Uniform,
+    );
+    assert_eq!(star.get_node_label(0), Some(&node_labels[0]));
+    assert_eq!(star.get_node_label_counter()[&node_labels[4]], 2);
+
+    // The generators draw labels following the distributions given with them.
+    let g: UnGraphMap<String, u32> = random_gnm_graph_with_rng(
+        &mut seeded_rng(7),
+        2000,
+        4000,
+        Labels::new(node_labels.clone(), LabelDistribution::Zipf(2f64)),
+        Labels::new(
+            edge_labels.clone(),
+            LabelDistribution::Weighted(vec![0f64, 1f64, 3f64]),
+        ),
+    );
+
+    let node_counter = g.get_node_label_counter();
+    assert_eq!(node_counter.values().sum::<usize>(), 2000);
+    assert!(node_counter[&node_labels[0]] > node_counter[&node_labels[1]]);
+    assert!(node_counter[&node_labels[1]] > node_counter[&node_labels[4]]);
+
+    let edge_counter = g.get_edge_label_counter();
+    assert_eq!(edge_counter.values().sum::<usize>(), 4000);
+    assert!(!edge_counter.contains_key(&1));
+    assert!(edge_counter[&3] > edge_counter[&2]);
+
+    let star: UnGraphMap<String, u32> = star_graph(
+        9,
+        Labels::new(node_labels.clone(), LabelDistribution::DegreeCorrelated),
+        Labels::new(edge_labels.clone(), LabelDistribution::DegreeCorrelated),
+    );
+    assert_eq!(star.get_node_label(0), Some(&node_labels[0]));
+    assert_eq!(star.get_node_label_counter()[&node_labels[4]], 2);
+    assert_eq!(star.get_edge_label_counter().values().sum::<usize>(), 9);
+
+    let stream: UnTemporalGraph<String, u32> = forest_fire_stream(
+        100,
+        0.3,
+        0.3,
+        Labels::new(node_labels.clone(), LabelDistribution::DegreeCorrelated),
+        edge_labels.clone(),
+    );
+    let hub = stream
+        .node_indices()
+        .max_by_key(|&id| {
+            stream
+                .edges()
+                .iter()
+                .filter(|e| e.get_start() == id || e.get_target() == id)
+                .count()
+        })
+        .unwrap();
+    assert_eq!(stream.get_node_label(hub), Some(&node_labels[0]));
+    assert!(stream
+        .edges()
+        .iter()
+        .all(|e| stream.get_edge_label(e).is_some()));
+
+    assert!(Labels::try_new(edge_labels.clone(), LabelDistribution::Weighted(vec![1f64])).is_err());
+    assert!(Labels::try_new(
+        edge_labels.clone(),
+        LabelDistribution::Weighted(vec![0f64; 3])
+    )
+    .is_err());
+
+    let nan = ::std::f64::NAN;
+    let inf = ::std::f64::INFINITY;
+    assert!(Labels::try_new(edge_labels.clone(), LabelDistribution::Zipf(nan)).is_err());
+    assert!(Labels::try_new(edge_labels.clone(), LabelDistribution::Zipf(-inf)).is_err());
+    assert!(Labels::try_new(edge_labels.clone(), LabelDistribution::Zipf(-1e4)).is_err());
+    assert!(Labels::try_new(
+        edge_labels.clone(),
+        LabelDistribution::Weighted(vec![1f64, nan, 1f64])
+    )
+    .is_err());
+    assert!(Labels::try_new(
+        edge_labels,
+        LabelDistribution::Weighted(vec![1f64, inf, 1f64])
+    )
+    .is_err());
+}
+
+#[test]
+fn test_query_extraction() {
+    let node_labels = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+    let edge_labels = vec![1, 2];
+
+    let data: UnGraphMap<String, u32> =
+        random_gnm_graph(60, 900, node_labels.clone(), edge_labels.clone());
+
+    for &strategy in &[QueryStrategy::RandomWalk, QueryStrategy::Bfs] {
+        for &density in &[QueryDensity::Sparse, QueryDensity::Dense] {
+            let generator = QueryGenerator::new(8)
+                .with_strategy(strategy)
+                .with_density(density);
+
+            let (query, embedding): (UnGraphMap<String, u32>, _) =
+                generator.extract(&data).unwrap();
+
+            assert_eq!(query.node_count(), 8);
+            assert_eq!(embedding.len(), 8);
+            assert!(query.edge_count() >= 7);
+
+            match density {
+                QueryDensity::Sparse => assert!(2 * query.edge_count() < 3 * 8),
+                QueryDensity::Dense => assert!(2 * query.edge_count() >= 3 * 8),
+            }
+
+            for i in query.node_indices() {
+                assert_eq!(
+                    query.get_node_label(i),
+                    data.get_node_label(embedding[i as usize])
+                );
+            }
+
+            for (s, t) in query.edge_indices() {
+                let (ds, dt) = (embedding[s as usize], embedding[t as usize]);
+                assert!(data.has_edge(ds, dt));
+                assert_eq!(query.get_edge_label(s, t), data.get_edge_label(ds, dt));
+            }
+
+            let mut reached = vec![0];
+            let mut head = 0;
+            while head < reached.len() {
+                for n in query.neighbors_iter(reached[head]) {
+                    if !reached.contains(&n) {
+                        reached.push(n);
+                    }
+                }
+                head += 1;
+            }
+            assert_eq!(reached.len(), 8);
+        }
+    }
+
+    let data: DiGraphMap<String, u32> =
+        random_gnm_graph(100, 300, node_labels.clone(), edge_labels.clone());
+    let (query, embedding): (DiGraphMap<String, u32>, _) = QueryGenerator::new(6)
+        .with_labels(false)
+        .extract(&data)
+        .unwrap();
+    assert!(query.node_labels().next().is_none());
+    for (s, t) in query.edge_indices() {
+        assert!(data.has_edge(embedding[s as usize], embedding[t as usize]));
+    }
+
+    let generator = QueryGenerator::new(6).with_strategy(QueryStrategy::Bfs);
+    let first: Option<Query<DefaultId, String, u32, Directed>> =
+        generator.extract_with_rng(&mut seeded_rng(7), &data);
+    let second: Option<Query<DefaultId, String, u32, Directed>> =
+        generator.extract_with_rng(&mut seeded_rng(7), &data);
+    assert_eq!(first.unwrap().1, second.unwrap().1);
+
+    let path: UnGraphMap<u8> = path_graph(5, Vec::new(), Vec::new());
+    assert!(QueryGenerator::new(6)
+        .extract::<_, _, _, Undirected, _>(&path)
+        .is_none());
+}
+
+#[test]
+fn test_temporal_stream() {
+    let stream: TypedDiTemporalGraph<u32, String, u32> = forest_fire_stream_with_rng(
+        &mut seeded_rng(3),
+        200,
+        0.35,
+        0.2,
+        vec!["a".to_owned(), "b".to_owned()],
+        vec![1, 2, 3],
+    );
+
+    assert_eq!(stream.node_count(), 200);
+    assert!(stream.edge_count() >= 199);
+    assert_eq!(stream.time_span(), Some((1, 199)));
+
+    for edge in stream.edges() {
+        assert_eq!(edge.get_timestamp(), edge.get_start() as u64);
+        assert!(edge.get_target() < edge.get_start());
+        assert!(stream.get_edge_label(edge).is_some());
+    }
+
+    let window = stream.edges_in_window(50, 100);
+    assert!(window
+        .iter()
+        .all(|e| (50..100).contains(&e.get_timestamp())));
+
+    let snapshot = stream.snapshot(50, 100);
+    assert_eq!(snapshot.edge_count(), window.len());
+    for edge in window {
+        let (s, t) = (edge.get_start(), edge.get_target());
+        assert!(snapshot.has_edge(s, t));
+        assert_eq!(snapshot.get_edge_label(s, t), stream.get_edge_label(edge));
+        assert_eq!(snapshot.get_node_label(s), stream.get_node_label(s));
+    }
+
+    assert_eq!(stream.to_graph_map().node_count(), 200);
+    assert_eq!(stream.to_graph_map().edge_count(), stream.edge_count());
+
+    let g: UnGraphMap<String, u32> = random_gnm_graph(50, 120, vec!["a".to_owned()], vec![1, 2]);
+    let stream: UnTemporalGraph<String, u32> = shuffled_edge_stream(&g);
+
+    assert_eq!(stream.node_count(), 50);
+    assert_eq!(stream.edge_count(), 120);
+    assert_eq!(stream.to_graph_map(), g);
+    assert_eq!(stream.snapshot(0, 120).edge_count(), 120);
+    assert_eq!(stream.snapshot(0, 60).edge_count(), 60);
+}
+
+#[test]
+fn test_try_random_gnm_graph() {
+    let rng = &mut seeded_rng(42);
+
+    let g: Result<UnGraphMap<Void>, _> = try_random_gnm_graph_with_rng(rng, 10, 45, vec![], vec![]);
+    assert_eq!(g.unwrap().edge_count(), 45);
+
+    let g: Result<UnGraphMap<Void>, _> = try_random_gnm_graph_with_rng(rng, 10, 46, vec![], vec![]);
+    assert!(g.is_err());
+
+    let g: Result<DiGraphMap<Void>, _> = try_random_gnm_graph_with_rng(rng, 10, 90, vec![], vec![]);
+    assert_eq!(g.unwrap().edge_count(), 90);
+}
+
+#[test]
+fn test_try_generators() {
+    let rng = &mut seeded_rng(42);
+
+    let g: Result<UnGraphMap<Void>, _> = try_cycle_graph_with_rng(rng, 2, vec![], vec![]);
+    assert!(g.is_err());
+
+    let g: Result<UnGraphMap<Void>, _> =
+        try_random_gnp_graph_with_rng(rng, 10, 1.5, vec![], vec![]);
+    assert!(g.is_err());
+
+    let g: Result<UnGraphMap<Void>, _> =
+        try_watts_strogatz_graph_with_rng(rng, 10, 10, 0.5, vec![], vec![]);
+    assert!(g.is_err());
+
+    let g: Result<DiGraphMap<Void>, _> =
+        try_watts_strogatz_graph_with_rng(rng, 10, 4, 0.5, vec![], vec![]);
+    assert!(g.is_err());
+
+    let g: Result<UnGraphMap<Void>, _> =
+        try_random_regular_graph_with_rng(rng, 5, 3, vec![], vec![]);
+    assert!(g.is_err());
+
+    let g: Result<UnGraphMap<Void>, _> =
+        try_barabasi_albert_graph_with_rng(rng, 10, 10, vec![], vec![]);
+    assert!(g.is_err());
+
+    let g: Result<(UnGraphMap<u32>, _), _> =
+        try_planted_partition_graph_with_rng(rng, 2, 5, 0.5, 1.5, vec![0, 1], Vec::<u32>::new());
+    assert!(g.is_err());
+
+    let stream: Result<UnTemporalGraph<Void>, _> =
+        try_forest_fire_stream_with_rng(rng, 10, 1.0, 0.5, vec![], vec![]);
+    assert!(stream.is_err());
+
+    assert!(RMatGenerator::try_new(0, 4).is_err());
+    assert!(RMatGenerator::try_new(62, usize::max_value()).is_err());
+    assert!(RMatGenerator::new(4, 4)
+        .try_with_probabilities(0.5, 0.5, 0.5, 0.5)
+        .is_err());
+
+    let generator = RMatGenerator::new(4, 4)
+        .with_probabilities(0.25, 0.25, 0.25, 0.25)
+        .with_noise(0.5);
+    let g: Result<DiStaticGraph<Void>, _> = generator.try_generate_with_rng(rng);
+    assert!(g.is_err());
+
+    assert!(QueryGenerator::try_new(0).is_err());
+
+    let data: UnGraphMap<Void> = complete_graph_unlabeled(5);
+    let query: Result<Option<(DiGraphMap<Void>, _)>, _> =
+        QueryGenerator::new(3).try_extract_with_rng(rng, &data);
+    assert!(query.is_err());
+}
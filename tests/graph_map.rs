@@ -407,3 +407,114 @@ fn test_stats() {
     assert_eq!(g.get_node_label_id_counter(), expected_counter);
     assert_eq!(g.get_edge_label_id_counter(), expected_counter)
 }
+
+#[test]
+fn test_static_iterators() {
+    let mut g = DiGraphMap::<&str>::new();
+    g.add_edge(0, 1, None);
+    g.add_edge(0, 2, None);
+    g.add_edge(2, 1, None);
+    g.add_node(3, None);
+
+    let mut nodes: Vec<_> = g.iter_node_indices().collect();
+    nodes.sort();
+    assert_eq!(nodes, vec![0, 1, 2, 3]);
+
+    let mut edges: Vec<_> = g.iter_edge_indices().collect();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 1), (0, 2), (2, 1)]);
+
+    assert_eq!(g.iter_neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(g.iter_in_neighbors(1).collect::<Vec<_>>(), vec![0, 2]);
+    assert_eq!(g.iter_neighbors(3).count(), 0);
+}
+
+#[test]
+fn test_extend() {
+    let mut g = UnGraphMap::<&str>::new();
+
+    let nodes = vec![(0, Some("a")), (1, Some("b")), (0, Some("c"))];
+    let stats = g.extend_nodes(nodes, DuplicatePolicy::Overwrite).unwrap();
+    assert_eq!(
+        (stats.inserted, stats.overwritten, stats.ignored),
+        (2, 1, 0)
+    );
+    assert_eq!(g.get_node_label(0), Some(&"c"));
+
+    let stats = g
+        .extend_nodes(vec![(1, Some("c")), (2, None)], DuplicatePolicy::Ignore)
+        .unwrap();
+    assert_eq!(
+        (stats.inserted, stats.overwritten, stats.ignored),
+        (1, 0, 1)
+    );
+    assert_eq!(g.get_node_label(1), Some(&"b"));
+
+    assert!(g
+        .extend_nodes(
+            vec![(3, None), (2, None), (4, None)],
+            DuplicatePolicy::Error
+        )
+        .is_err());
+    assert!(g.has_node(3));
+    assert!(!g.has_node(4));
+
+    let edges = vec![(0, 1, Some("x")), (1, 0, Some("y")), (2, 5, None)];
+    let stats = g.extend_edges(edges, DuplicatePolicy::Ignore).unwrap();
+    assert_eq!((stats.inserted, stats.duplicates()), (2, 1));
+    assert_eq!(g.get_edge_label(0, 1), Some(&"x"));
+    assert!(g.has_node(5));
+
+    let stats = g
+        .extend_edges(vec![(1, 0, Some("y"))], DuplicatePolicy::Overwrite)
+        .unwrap();
+    assert_eq!(stats.overwritten, 1);
+    assert_eq!(g.get_edge_label(0, 1), Some(&"y"));
+
+    assert!(g
+        .extend_edges(vec![(5, 2, None)], DuplicatePolicy::Error)
+        .is_err());
+    assert_eq!(g.edge_count(), 2);
+}
+
+#[test]
+fn test_neighbors_with_labels() {
+    let mut g = DiGraphMap::<&str>::new();
+    g.add_edge(0, 1, Some("a"));
+    g.add_edge(0, 2, Some("b"));
+    g.add_edge(0, 3, None);
+    g.add_edge(2, 1, Some("a"));
+
+    let a = Some(0);
+    let b = Some(1);
+
+    assert_eq!(
+        g.neighbors_with_labels(0).collect::<Vec<_>>(),
+        vec![(1, a), (2, b), (3, None)]
+    );
+    assert_eq!(
+        g.in_neighbors_with_labels(1).collect::<Vec<_>>(),
+        vec![(0, a), (2, a)]
+    );
+    assert_eq!(
+        g.neighbors_with_edge_label(0, 0).collect::<Vec<_>>(),
+        vec![1]
+    );
+    assert_eq!(
+        g.in_neighbors_with_edge_label(2, 1).collect::<Vec<_>>(),
+        vec![0]
+    );
+
+    let mut g = UnGraphMap::<&str>::new();
+    g.add_edge(1, 0, Some("a"));
+    g.add_edge(1, 2, Some("b"));
+
+    assert_eq!(
+        g.neighbors_with_labels(1).collect::<Vec<_>>(),
+        vec![(0, a), (2, b)]
+    );
+    assert_eq!(
+        g.neighbors_with_edge_label(2, 1).collect::<Vec<_>>(),
+        vec![1]
+    );
+}
@@ -1,10 +1,24 @@
 extern crate rust_graph;
 extern crate tempfile;
 
+use std::fs;
+
 use rust_graph::graph_gen::{random_gnm_graph, random_gnm_graph_unlabeled};
 use rust_graph::graph_impl::{DiGraphMap, GraphMap, UnGraphMap};
+use rust_graph::graph_impl::{DiStaticGraph, UnStaticGraph};
+use rust_graph::graph_impl::{EdgeVec, Graph, TypedUnGraphMap};
+use rust_graph::io::csv::reader::GraphReader;
+use rust_graph::io::csv::writer::GraphWriter;
+use rust_graph::io::ldbc::node::Node;
+use rust_graph::io::ldbc::relation::Relation;
+use rust_graph::io::ldbc::Scheme;
+use rust_graph::io::serde::{export_graph, import_graph, read_header, FormatError};
 use rust_graph::io::{read_from_csv, write_to_csv};
+use rust_graph::io::{read_from_json, write_to_json, JsonFormat};
+use rust_graph::io::{read_from_matrix_market, write_to_matrix_market};
+use rust_graph::io::{read_from_metis, write_to_metis};
 use rust_graph::prelude::*;
+use rust_graph::UnStaticGraphConverter;
 
 use tempfile::TempDir;
 
@@ -66,3 +80,461 @@ fn test_cvs_labeled() {
     assert!(read_from_csv(&mut g_, Some(path_to_nodes), path_to_edges).is_ok());
     assert_eq!(g, g_);
 }
+
+#[test]
+fn test_cvs_duplicates() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+    let path_to_nodes = tmp_dir_path.join("nodes.csv");
+    let path_to_edges = tmp_dir_path.join("edges.csv");
+
+    fs::write(&path_to_nodes, "nodeId:ID,:LABEL\n0,a\n1,b\n0,c\n").unwrap();
+    fs::write(&path_to_edges, ":START_ID,:END_ID,:TYPE\n0,1,x\n1,0,y\n").unwrap();
+
+    let mut g = UnGraphMap::<String>::new();
+    assert!(read_from_csv(&mut g, Some(&path_to_nodes), &path_to_edges).is_ok());
+    assert_eq!(g.get_node_label(0).map(|l| l.as_str()), Some("c"));
+    assert_eq!(g.get_edge_label(0, 1).map(|l| l.as_str()), Some("y"));
+
+    let reader = GraphReader::new(Some(&path_to_nodes), &path_to_edges);
+
+    let mut g = UnGraphMap::<String>::new();
+    assert!(reader
+        .with_duplicate_policy(DuplicatePolicy::Ignore)
+        .read(&mut g)
+        .is_ok());
+    assert_eq!(g.get_node_label(0).map(|l| l.as_str()), Some("a"));
+    assert_eq!(g.get_edge_label(0, 1).map(|l| l.as_str()), Some("x"));
+
+    let reader = GraphReader::new(Some(&path_to_nodes), &path_to_edges);
+
+    let mut g = UnGraphMap::<String>::new();
+    assert!(reader
+        .with_duplicate_policy(DuplicatePolicy::Error)
+        .read(&mut g)
+        .is_err());
+
+    // The edges before an invalid record are inserted.
+    fs::write(
+        &path_to_edges,
+        ":START_ID,:END_ID,:TYPE\n0,1,x\nz,0,y\n1,2,x\n",
+    )
+    .unwrap();
+
+    let mut g = UnGraphMap::<String>::new();
+    assert!(read_from_csv(&mut g, None, &path_to_edges).is_err());
+    assert!(g.has_edge(0, 1));
+    assert!(!g.has_edge(1, 2));
+}
+
+#[test]
+fn test_json() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+
+    let node_labels = &vec!["a".to_owned(), "b".to_owned()];
+    let edge_labels = &vec![1, 2, 3];
+
+    for &format in &[JsonFormat::NodeLink, JsonFormat::Adjacency] {
+        let path = tmp_dir_path.join("graph.json");
+
+        let g: UnGraphMap<String, u32> =
+            random_gnm_graph(10, 20, node_labels.clone(), edge_labels.clone());
+        assert!(write_to_json(&g, &path, format).is_ok());
+
+        let mut g_ = GraphMap::with_label_map(node_labels.into(), edge_labels.into());
+        assert!(read_from_json(&mut g_, &path, format).is_ok());
+        assert_eq!(g, g_);
+
+        let g: DiGraphMap<Void> = random_gnm_graph_unlabeled(10, 20);
+        assert!(write_to_json(&g, &path, format).is_ok());
+
+        let mut g_ = GraphMap::new();
+        assert!(read_from_json(&mut g_, &path, format).is_ok());
+        assert_eq!(g, g_);
+
+        let mut g_: UnGraphMap<Void> = GraphMap::new();
+        let err = read_from_json(&mut g_, &path, format).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    let path = tmp_dir_path.join("networkx.json");
+    fs::write(
+        &path,
+        r#"{"directed": false, "multigraph": false, "graph": {"name": "g"},
+            "nodes": [{"id": 0, "label": "a", "color": "red"}, {"id": 1}, {"id": 2}],
+            "links": [{"source": 0, "target": 1, "label": "x", "weight": 2},
+                      {"source": 1, "target": 2}]}"#,
+    )
+    .unwrap();
+
+    let mut g: UnGraphMap<String> = GraphMap::new();
+    assert!(read_from_json(&mut g, &path, JsonFormat::NodeLink).is_ok());
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.get_node_label(0), Some(&"a".to_owned()));
+    assert_eq!(g.get_edge_label(1, 0), Some(&"x".to_owned()));
+    assert_eq!(g.get_edge_label(1, 2), None);
+
+    fs::write(
+        &path,
+        r#"{"directed": false, "multigraph": true, "graph": {}, "nodes": [], "links": []}"#,
+    )
+    .unwrap();
+    assert!(read_from_json(&mut g, &path, JsonFormat::NodeLink).is_err());
+
+    // Undirected edges listed only at their larger end.
+    let path = tmp_dir_path.join("adjacency.json");
+    fs::write(
+        &path,
+        r#"{"directed": false, "multigraph": false, "graph": {},
+            "nodes": [{"id": 0}, {"id": 1}, {"id": 2}],
+            "adjacency": [[], [{"id": 0, "label": "x"}], [{"id": 0}, {"id": 1}]]}"#,
+    )
+    .unwrap();
+
+    let mut g: UnGraphMap<String> = GraphMap::new();
+    assert!(read_from_json(&mut g, &path, JsonFormat::Adjacency).is_ok());
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(g.get_edge_label(0, 1), Some(&"x".to_owned()));
+    assert_eq!(g.get_edge_label(0, 2), None);
+}
+
+#[test]
+fn test_metis() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.metis");
+
+    // The weighted example from the METIS manual, with a comment line.
+    let content = "% weighted graph\n\
+                   7 11 011\n\
+                   4 5 1 3 2 2 1\n\
+                   2 1 1 3 2 4 1\n\
+                   5 5 3 4 2 2 2 1 2\n\
+                   1 2 1 3 2 6 2 7 5\n\
+                   1 1 1 3 3 6 2\n\
+                   6 5 2 4 2 7 6\n\
+                   2 6 6 4 5\n";
+    fs::write(&path, content).unwrap();
+
+    let g: UnStaticGraph<u32> = read_from_metis(&path).unwrap();
+
+    assert_eq!(g.node_count(), 7);
+    assert_eq!(g.edge_count(), 11);
+    assert_eq!(&g.neighbors(0)[..], &[1, 2, 4]);
+    assert_eq!(&g.neighbors(6)[..], &[3, 5]);
+    assert_eq!(g.get_node_label(0), Some(&4));
+    assert_eq!(g.get_node_label(6), Some(&2));
+    assert_eq!(g.get_edge_label(0, 4), Some(&1));
+    assert_eq!(g.get_edge_label(3, 6), Some(&5));
+    assert_eq!(g.get_edge_label(6, 3), Some(&5));
+
+    let path_ = tmp_dir.path().join("graph_.metis");
+    assert!(write_to_metis(&g, &path_).is_ok());
+
+    let g_: UnStaticGraph<u32> = read_from_metis(&path_).unwrap();
+    assert_eq!(g, g_);
+
+    fs::write(&path, "3 2\n2\n1 3\n").unwrap();
+    assert!(read_from_metis::<DefaultId, u32, u32, _>(&path).is_err());
+
+    // Invalid fmt digits, and ncon without vertex weights.
+    for header in &["3 2 2", "3 2 0a1", "3 2 001 1"] {
+        fs::write(&path, format!("{}\n2 1\n1 1 3 1\n2 1\n", header)).unwrap();
+        assert!(read_from_metis::<DefaultId, u32, u32, _>(&path).is_err());
+    }
+
+    fs::write(&path, "3 2 001 0\n2 1\n1 1 3 1\n2 1\n").unwrap();
+    assert!(read_from_metis::<DefaultId, u32, u32, _>(&path).is_ok());
+}
+
+#[test]
+fn test_matrix_market() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.mtx");
+
+    let content = "%%MatrixMarket matrix coordinate integer general\n\
+                   % a comment\n\
+                   3 3 4\n\
+                   1 2 7\n\
+                   2 3 8\n\
+                   3 1 9\n\
+                   1 3 7\n";
+    fs::write(&path, content).unwrap();
+
+    let g: DiStaticGraph<Void, u32> = read_from_matrix_market(&path).unwrap();
+
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(&g.neighbors(0)[..], &[1, 2]);
+    assert_eq!(&g.in_neighbors(0)[..], &[2]);
+    assert_eq!(g.get_edge_label(0, 1), Some(&7));
+    assert_eq!(g.get_edge_label(2, 0), Some(&9));
+
+    let path_ = tmp_dir.path().join("graph_.mtx");
+    assert!(write_to_matrix_market(&g, &path_).is_ok());
+
+    let g_: DiStaticGraph<Void, u32> = read_from_matrix_market(&path_).unwrap();
+    assert_eq!(g, g_);
+
+    let content = "%%MatrixMarket matrix coordinate pattern symmetric\n\
+                   4 4 3\n\
+                   2 1\n\
+                   3 1\n\
+                   4 3\n";
+    fs::write(&path, content).unwrap();
+
+    let g: UnStaticGraph<Void> = read_from_matrix_market(&path).unwrap();
+
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(&g.neighbors(0)[..], &[1, 2]);
+    assert_eq!(&g.neighbors(2)[..], &[0, 3]);
+
+    assert!(write_to_matrix_market(&g, &path_).is_ok());
+
+    let g_: UnStaticGraph<Void> = read_from_matrix_market(&path_).unwrap();
+    assert_eq!(g, g_);
+
+    let mut g = UnGraphMap::<Void, &str>::new();
+    g.add_edge(0, 1, Some("1.5"));
+    g.add_edge(1, 2, Some("x"));
+    let g = UnStaticGraphConverter::new(g, false, false).convert();
+    let err = write_to_matrix_market(&g, &path_).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_ldbc_scheme() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+
+    fs::write(
+        tmp_dir_path.join("person_knows_person_0_0.csv"),
+        "Person.id|Person.id\n1|2\n2|3\n",
+    )
+    .unwrap();
+
+    let g = Scheme::init()
+        .from_path::<DefaultId, Undirected, _>(tmp_dir_path)
+        .unwrap();
+
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.get_node_label(0), Some(&"person".to_owned()));
+    assert_eq!(g.get_edge_label(0, 1), Some(&"knows".to_owned()));
+
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+
+    fs::write(tmp_dir_path.join("user.csv"), "id,kind\n1,admin\n2,guest\n").unwrap();
+    fs::write(tmp_dir_path.join("follows_1.csv"), "src,dst\n1,2\n").unwrap();
+
+    let description = tmp_dir_path.join("scheme.toml");
+    fs::write(
+        &description,
+        r#"
+        delimiter = ","
+
+        [[nodes]]
+        name = "user"
+        label_index = 1
+        file_name_start = "user"
+
+        [[relations]]
+        start_label = "user"
+        edge_label = "follows"
+        target_label = "user"
+        file_name_start = "follows"
+        "#,
+    )
+    .unwrap();
+
+    let g = Scheme::from_file(&description)
+        .unwrap()
+        .from_path::<DefaultId, Directed, _>(tmp_dir_path)
+        .unwrap();
+
+    assert_eq!(g.node_count(), 2);
+    assert_eq!(g.edge_count(), 1);
+    assert_eq!(g.get_node_label(0), Some(&"admin".to_owned()));
+    assert_eq!(g.get_node_label(1), Some(&"guest".to_owned()));
+    assert_eq!(g.get_edge_label(0, 1), Some(&"follows".to_owned()));
+
+    let mut scheme = Scheme::new(b',');
+    scheme
+        .add_node(Node::new("user", 0, Some(1), "user"))
+        .add_relation(Relation::new("user", "user", "follows", 0, 1, "follows"));
+
+    let g_ = scheme
+        .from_path::<DefaultId, Directed, _>(tmp_dir_path)
+        .unwrap();
+
+    assert_eq!(g, g_);
+
+    assert!("delimiter = \"||\"".parse::<Scheme>().is_err());
+}
+
+#[test]
+fn test_ldbc_load() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+
+    fs::write(
+        tmp_dir_path.join("person_0_0.csv"),
+        "id|firstName|lastName|gender|birthday|creationDate|locationIP|browserUsed\n\
+         1|Alice|A|female|1990-01-01|2010-01-01|1.1.1.1|Firefox\n\
+         2|Bob|B|male|1991-01-01|2010-01-02|1.1.1.2|Chrome\n",
+    )
+    .unwrap();
+    fs::write(
+        tmp_dir_path.join("person_knows_person_0_0.csv"),
+        "Person.id|Person.id\n1|2\n2|3\n",
+    )
+    .unwrap();
+    fs::write(
+        tmp_dir_path.join("person_hasInterest_tag_0_0.csv"),
+        "Person.id|Tag.id\n1|7\n",
+    )
+    .unwrap();
+
+    let data = Scheme::init()
+        .load::<DefaultId, Undirected, _>(tmp_dir_path)
+        .unwrap();
+    let g = data.get_graph();
+
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.get_node_label(0), Some(&"person".to_owned()));
+    assert_eq!(g.get_node_label(2), Some(&"tag".to_owned()));
+    assert_eq!(data.get_node_property(0, "firstName"), Some("Alice"));
+    assert_eq!(data.get_node_property(1, "gender"), Some("male"));
+    assert_eq!(data.get_node_property(1, "locationIP"), None);
+
+    let report = data.get_report();
+    let files: Vec<_> = report
+        .get_files()
+        .iter()
+        .map(|f| {
+            (
+                f.get_path().file_name().unwrap().to_str().unwrap(),
+                f.num_of_records(),
+                f.num_of_unresolved(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        files,
+        vec![
+            ("person_0_0.csv", 2, 0),
+            ("person_hasInterest_tag_0_0.csv", 1, 0),
+            ("person_knows_person_0_0.csv", 2, 1),
+        ]
+    );
+    assert_eq!(report.num_of_unresolved(), 1);
+    assert_eq!(report.num_of_implicit_nodes(), 1);
+
+    fs::write(
+        tmp_dir_path.join("person_knows_person_0_0.csv"),
+        "Person.id|Person.id\n1|2\n2|1\n",
+    )
+    .unwrap();
+
+    let g = Scheme::init()
+        .from_path::<DefaultId, Undirected, _>(tmp_dir_path)
+        .unwrap();
+    assert_eq!(g.edge_count(), 2);
+
+    assert!(Scheme::init()
+        .with_duplicate_policy(DuplicatePolicy::Error)
+        .from_path::<DefaultId, Undirected, _>(tmp_dir_path)
+        .is_err());
+}
+
+#[test]
+fn test_graph_file() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.bin");
+
+    let node_labels = vec!["a".to_owned(), "b".to_owned()];
+    let edge_labels = vec![1, 2, 3];
+
+    let g: UnGraphMap<String, u32> = random_gnm_graph(10, 20, node_labels, edge_labels);
+    assert!(export_graph(&g, &path).is_ok());
+
+    let header = read_header(&path).unwrap();
+    assert_eq!(
+        header.get_description().get_implementation(),
+        Graph::GraphMap
+    );
+    assert!(!header.get_description().is_directed());
+
+    let g_: UnGraphMap<String, u32> = import_graph(&path).unwrap();
+    assert_eq!(g, g_);
+
+    match import_graph::<DiGraphMap<String, u32>, _>(&path) {
+        Err(FormatError::Mismatch { field, .. }) => assert_eq!(field, "directedness"),
+        _ => panic!("Loading an undirected graph as directed."),
+    }
+
+    match import_graph::<TypedUnGraphMap<u16, String, u32>, _>(&path) {
+        Err(FormatError::Mismatch { field, .. }) => assert_eq!(field, "id size"),
+        _ => panic!("Loading a graph with different id type."),
+    }
+
+    match import_graph::<UnGraphMap<String, String>, _>(&path) {
+        Err(FormatError::Mismatch { field, .. }) => assert_eq!(field, "edge label type"),
+        _ => panic!("Loading a graph with different edge label type."),
+    }
+
+    match import_graph::<UnStaticGraph<String, u32>, _>(&path) {
+        Err(FormatError::Mismatch { field, .. }) => assert_eq!(field, "implementation"),
+        _ => panic!("Loading a graph map as static graph."),
+    }
+
+    let mut bytes = fs::read(&path).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    fs::write(&path, &bytes).unwrap();
+
+    match import_graph::<UnGraphMap<String, u32>, _>(&path) {
+        Err(FormatError::ChecksumMismatch { .. }) => {}
+        _ => panic!("Loading a corrupted graph."),
+    }
+
+    fs::write(&path, "not a graph").unwrap();
+
+    match import_graph::<UnGraphMap<String, u32>, _>(&path) {
+        Err(FormatError::NotAGraphFile) => {}
+        _ => panic!("Loading a file without magic number."),
+    }
+
+    // The edge (0,1) has no reverse edge.
+    let g: UnStaticGraph<Void> = UnStaticGraph::new(2, EdgeVec::new(vec![0, 1, 1], vec![1]), None);
+    assert!(export_graph(&g, &path).is_ok());
+
+    match import_graph::<UnStaticGraph<Void>, _>(&path) {
+        Err(FormatError::InvalidGraph(_)) => {}
+        _ => panic!("Loading an inconsistent graph."),
+    }
+}
+
+#[test]
+fn test_invalid_input() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+    let edges = tmp_dir_path.join("edges.csv");
+
+    assert!(GraphReader::<DefaultId, Void, Void>::try_with_separator(None, &edges, "tab").is_ok());
+    assert!(GraphReader::<DefaultId, Void, Void>::try_with_separator(None, &edges, "||").is_err());
+
+    let g = UnGraphMap::<Void>::new();
+    assert!(GraphWriter::try_with_separator(&g, &edges, &edges, "space").is_ok());
+    assert!(GraphWriter::try_with_separator(&g, &edges, &edges, "").is_err());
+
+    fs::write(&edges, "0,1\n").unwrap();
+    assert!(Scheme::init()
+        .load::<DefaultId, Undirected, _>(&edges)
+        .is_err());
+}
@@ -0,0 +1,109 @@
+extern crate rand;
+extern crate rust_graph;
+
+use std::collections::BTreeSet;
+
+use rand::Rng;
+
+use rust_graph::graph_gen::seeded_rng;
+use rust_graph::set_ops::*;
+
+// A sorted set of ids below `max`, each of which is in the set with probability `1 / gap`.
+fn random_set<R: Rng>(rng: &mut R, max: u32, gap: u32) -> Vec<u32> {
+    (0..max).filter(|_| rng.gen_range(0, gap) == 0).collect()
+}
+
+fn to_set(slice: &[u32]) -> BTreeSet<u32> {
+    slice.iter().cloned().collect()
+}
+
+#[test]
+fn test_intersect() {
+    let a = vec![1u32, 3, 4, 5, 7, 8, 9, 10, 12];
+    let b = vec![0u32, 2, 3, 5, 6, 9, 10, 11, 12, 13];
+    let expected = vec![3, 5, 9, 10, 12];
+
+    assert_eq!(intersect_merge(&a, &b), expected);
+    assert_eq!(intersect_galloping(&a, &b), expected);
+    assert_eq!(intersect_block(&a, &b), expected);
+    assert_eq!(intersect(&a, &b), expected);
+    assert_eq!(intersect(&b, &a), expected);
+
+    assert_eq!(intersect_count_merge(&a, &b), 5);
+    assert_eq!(intersect_count_galloping(&a, &b), 5);
+    assert_eq!(intersect_count_block(&a, &b), 5);
+    assert_eq!(intersect_count(&a, &b), 5);
+
+    assert!(intersect(&a, &[]).is_empty());
+    assert_eq!(intersect_count::<u32>(&[], &[]), 0);
+}
+
+#[test]
+fn test_random_intersect() {
+    let mut rng = seeded_rng(7);
+
+    // Similar sizes are merged, and skewed sizes are galloped through by `intersect`.
+    for &(gap_a, gap_b) in [(2, 2), (3, 5), (1, 64), (100, 2), (1000, 1)].iter() {
+        let a = random_set(&mut rng, 5000, gap_a);
+        let b = random_set(&mut rng, 5000, gap_b);
+        let expected: Vec<u32> = to_set(&a).intersection(&to_set(&b)).cloned().collect();
+
+        assert_eq!(intersect_merge(&a, &b), expected);
+        assert_eq!(intersect_galloping(&a, &b), expected);
+        assert_eq!(intersect_block(&a, &b), expected);
+        assert_eq!(intersect(&a, &b), expected);
+
+        assert_eq!(intersect_count_merge(&a, &b), expected.len());
+        assert_eq!(intersect_count_galloping(&a, &b), expected.len());
+        assert_eq!(intersect_count_block(&a, &b), expected.len());
+        assert_eq!(intersect_count(&a, &b), expected.len());
+
+        let mut buffer = vec![42];
+        intersect_into(&a, &b, &mut buffer);
+        assert_eq!(buffer, expected);
+    }
+}
+
+#[test]
+fn test_intersect_all() {
+    let mut rng = seeded_rng(11);
+    let sets: Vec<Vec<u32>> = [2, 3, 1, 40]
+        .iter()
+        .map(|&gap| random_set(&mut rng, 10000, gap))
+        .collect();
+    let slices: Vec<&[u32]> = sets.iter().map(|set| set.as_slice()).collect();
+
+    let expected: Vec<u32> = (0..10000)
+        .filter(|x| sets.iter().all(|set| set.contains(x)))
+        .collect();
+
+    assert_eq!(intersect_all(&slices), expected);
+    assert_eq!(intersect_all_count(&slices), expected.len());
+
+    assert_eq!(intersect_all(&slices[..1]), sets[0]);
+    assert_eq!(intersect_all_count(&slices[..1]), sets[0].len());
+    assert!(intersect_all::<u32>(&[]).is_empty());
+    assert_eq!(intersect_all_count::<u32>(&[]), 0);
+}
+
+#[test]
+fn test_difference_and_union() {
+    let mut rng = seeded_rng(13);
+
+    for &(gap_a, gap_b) in [(2, 3), (100, 1), (1, 100)].iter() {
+        let a = random_set(&mut rng, 5000, gap_a);
+        let b = random_set(&mut rng, 5000, gap_b);
+        let (set_a, set_b) = (to_set(&a), to_set(&b));
+
+        let expected: Vec<u32> = set_a.difference(&set_b).cloned().collect();
+        assert_eq!(difference(&a, &b), expected);
+        assert_eq!(difference_count(&a, &b), expected.len());
+
+        let expected: Vec<u32> = set_a.union(&set_b).cloned().collect();
+        assert_eq!(union(&a, &b), expected);
+        assert_eq!(union_count(&a, &b), expected.len());
+    }
+
+    assert_eq!(difference(&[1u32, 2, 3], &[]), vec![1, 2, 3]);
+    assert_eq!(union(&[], &[1u32, 2]), vec![1, 2]);
+}
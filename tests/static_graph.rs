@@ -4,11 +4,14 @@ extern crate rust_graph;
 use rust_graph::prelude::*;
 
 use rust_graph::generic::DefaultId;
+use rust_graph::graph_gen::random_gnm_graph;
 use rust_graph::graph_impl::Edge;
 use rust_graph::graph_impl::static_graph::EdgeVec;
+use rust_graph::graph_impl::static_graph::LabelIndex;
 use rust_graph::graph_impl::static_graph::StaticNode;
 use rust_graph::map::SetMap;
-use rust_graph::{DiStaticGraph, UnStaticGraph};
+use rust_graph::UnStaticGraphConverter;
+use rust_graph::{DiStaticGraph, UnGraphMap, UnStaticGraph};
 
 #[test]
 fn test_directed() {
@@ -132,3 +135,325 @@ fn test_clone() {
     let g = DiStaticGraph::<Void>::new(3, edge_vec, Some(in_edge_vec));
     assert_eq!(g, g.clone());
 }
+
+#[test]
+fn test_try_accessors() {
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let g = DiStaticGraph::<Void>::new(3, edge_vec, Some(in_edge_vec));
+
+    assert_eq!(g.try_neighbors(0).unwrap(), &[1, 2]);
+    assert_eq!(g.try_degree(1).unwrap(), 1);
+    assert_eq!(g.try_in_neighbors(2).unwrap(), &[0]);
+
+    assert!(g.try_neighbors(3).is_err());
+    assert!(g.try_degree(3).is_err());
+    assert!(g.try_in_neighbors(3).is_err());
+
+    assert_eq!(
+        g.get_node(0).try_unwrap_staticnode().unwrap(),
+        StaticNode::new(0, None)
+    );
+    assert!(g.get_node(0).try_unwrap_nodemap().is_err());
+    assert!(g.get_node(3).try_unwrap_staticnode().is_err());
+    assert!(g.get_edge(0, 1).try_unwrap_staticedge().is_ok());
+    assert!(g.get_edge(1, 2).try_unwrap_staticedge().is_err());
+}
+
+#[test]
+fn test_validate() {
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let g = DiStaticGraph::<Void>::try_new(3, edge_vec, Some(in_edge_vec)).unwrap();
+    assert!(g.validate().is_ok());
+
+    // Decreasing offsets.
+    let edge_vec = EdgeVec::new(vec![0, 3, 2, 4], vec![1, 2, 0, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    assert!(DiStaticGraph::<Void>::try_new(3, edge_vec, Some(in_edge_vec)).is_err());
+
+    // Unsorted neighbors.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![2, 1, 0, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    assert!(DiStaticGraph::<Void>::try_new(3, edge_vec, Some(in_edge_vec)).is_err());
+
+    // Out-of-range neighbor.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 3, 0, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    assert!(DiStaticGraph::<Void>::try_new(3, edge_vec, Some(in_edge_vec)).is_err());
+
+    // In-edges not matching the edges.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 1, 3, 4], vec![1, 0, 2, 0]);
+    assert!(DiStaticGraph::<Void>::try_new(3, edge_vec, Some(in_edge_vec)).is_err());
+
+    // Missing in-edges.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    assert!(DiStaticGraph::<Void>::try_new(3, edge_vec, None).is_err());
+
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    assert!(UnStaticGraph::<Void>::try_new(3, edge_vec, None).is_ok());
+
+    // Asymmetric undirected edges.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 1]);
+    assert!(UnStaticGraph::<Void>::try_new(3, edge_vec, None).is_err());
+
+    // Too few node labels.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let labels = vec![0, 1];
+    let node_label_map = SetMap::from_vec(vec!["a", "b"]);
+    assert!(UnStaticGraph::<&str>::try_with_labels(
+        3,
+        edge_vec,
+        None,
+        labels,
+        node_label_map,
+        SetMap::new()
+    )
+    .is_err());
+
+    // Node label id out of the label map.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let labels = vec![0, 1, 2];
+    let node_label_map = SetMap::from_vec(vec!["a", "b"]);
+    assert!(UnStaticGraph::<&str>::try_with_labels(
+        3,
+        edge_vec,
+        None,
+        labels,
+        node_label_map,
+        SetMap::new()
+    )
+    .is_err());
+
+    // Unlabeled node.
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let labels = vec![0, 1, DefaultId::max_value()];
+    let node_label_map = SetMap::from_vec(vec!["a", "b"]);
+    assert!(UnStaticGraph::<&str>::try_with_labels(
+        3,
+        edge_vec,
+        None,
+        labels,
+        node_label_map,
+        SetMap::new()
+    )
+    .is_ok());
+
+    // Edge label id out of the label map.
+    let edge_vec = EdgeVec::with_labels(vec![0, 2, 3, 4], vec![1, 2, 0, 0], vec![0, 1, 0, 1]);
+    let edge_label_map = SetMap::from_vec(vec!["x"]);
+    assert!(UnStaticGraph::<&str>::try_with_labels(
+        3,
+        edge_vec,
+        None,
+        vec![0; 3],
+        SetMap::from_vec(vec!["a"]),
+        edge_label_map
+    )
+    .is_err());
+}
+
+#[test]
+fn test_static_iterators() {
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let g = DiStaticGraph::<Void>::new(3, edge_vec, Some(in_edge_vec));
+
+    assert_eq!(
+        g.iter_node_indices().collect::<Vec<_>>(),
+        g.node_indices().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        g.iter_edge_indices().collect::<Vec<_>>(),
+        g.edge_indices().collect::<Vec<_>>()
+    );
+
+    for id in g.node_indices() {
+        assert_eq!(
+            g.iter_neighbors(id).collect::<Vec<_>>(),
+            g.neighbors_iter(id).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            g.iter_in_neighbors(id).collect::<Vec<_>>(),
+            g.in_neighbors_iter(id).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_edge_index() {
+    let edge_vec = EdgeVec::with_labels(vec![0, 2, 3, 4], vec![1, 2, 0, 0], vec![0, 1, 0, 1]);
+    let g = UnStaticGraph::<&str>::with_labels(
+        3,
+        edge_vec,
+        None,
+        vec![0, 0, 0],
+        SetMap::from_vec(vec!["a"]),
+        SetMap::from_vec(vec!["x", "y"]),
+    );
+
+    for index in 0..g.get_edge_vec().len() {
+        let (start, target) = g.find_edge(index).unwrap();
+        assert_eq!(g.find_edge_index(start, target), Some(index));
+        assert_eq!(
+            g.get_edge_label_by_index(index),
+            g.get_edge_label(start, target)
+        );
+
+        let reverse = g.find_reverse_edge_index(index).unwrap();
+        assert_eq!(g.find_edge(reverse), Some((target, start)));
+    }
+
+    assert_eq!(g.find_edge(1), Some((0, 2)));
+    assert_eq!(g.get_edge_label_by_index(1), Some(&"y"));
+    assert_eq!(g.find_reverse_edge_index(1), Some(3));
+    assert_eq!(g.find_edge(4), None);
+
+    // Node 1 has no out-edge.
+    let edge_vec = EdgeVec::new(vec![0, 2, 2, 3], vec![1, 2, 1]);
+    let in_edge_vec = EdgeVec::new(vec![0, 0, 2, 3], vec![0, 2, 0]);
+    let g = DiStaticGraph::<Void>::new(3, edge_vec, Some(in_edge_vec));
+
+    assert_eq!(g.find_edge(2), Some((2, 1)));
+    assert_eq!(g.find_in_edge_index(2), Some(1));
+    assert_eq!(g.find_out_edge_index(1), Some(2));
+
+    for index in 0..g.get_edge_vec().len() {
+        let in_index = g.find_in_edge_index(index).unwrap();
+        assert_eq!(g.find_out_edge_index(in_index), Some(index));
+    }
+}
+
+#[test]
+fn test_neighbors_with_labels() {
+    let edge_vec = EdgeVec::with_labels(vec![0, 2, 3, 4], vec![1, 2, 0, 0], vec![0, 1, 0, 1]);
+    let g = UnStaticGraph::<&str>::with_labels(
+        3,
+        edge_vec,
+        None,
+        vec![0, 0, 0],
+        SetMap::from_vec(vec!["a"]),
+        SetMap::from_vec(vec!["x", "y"]),
+    );
+
+    assert_eq!(
+        g.neighbors_with_labels(0).collect::<Vec<_>>(),
+        vec![(1, Some(0)), (2, Some(1))]
+    );
+    assert_eq!(
+        g.neighbors_with_edge_label(0, 1).collect::<Vec<_>>(),
+        vec![2]
+    );
+
+    let g: &dyn GraphTrait<DefaultId> = &g;
+    assert_eq!(
+        g.neighbors_with_labels(2).collect::<Vec<_>>(),
+        vec![(0, Some(1))]
+    );
+
+    let edge_vec = EdgeVec::with_labels(vec![0, 2, 2, 3], vec![1, 2, 1], vec![0, 1, 1]);
+    let in_edge_vec = EdgeVec::with_labels(vec![0, 0, 2, 3], vec![0, 2, 0], vec![0, 1, 1]);
+    let g = DiStaticGraph::<&str>::with_labels(
+        3,
+        edge_vec,
+        Some(in_edge_vec),
+        vec![0, 0, 0],
+        SetMap::from_vec(vec!["a"]),
+        SetMap::from_vec(vec!["x", "y"]),
+    );
+
+    let g: &dyn DiGraphTrait<DefaultId> = &g;
+    assert_eq!(
+        g.in_neighbors_with_labels(1).collect::<Vec<_>>(),
+        vec![(0, Some(0)), (2, Some(1))]
+    );
+    assert_eq!(
+        g.in_neighbors_with_edge_label(1, 1).collect::<Vec<_>>(),
+        vec![2]
+    );
+
+    // Unlabeled edges.
+    let edge_vec = EdgeVec::new(vec![0, 1, 2], vec![1, 0]);
+    let g = UnStaticGraph::<Void>::new(2, edge_vec, None);
+    assert_eq!(
+        g.neighbors_with_labels(0).collect::<Vec<_>>(),
+        vec![(1, None)]
+    );
+    assert_eq!(g.neighbors_with_edge_label(0, 0).count(), 0);
+}
+
+#[test]
+fn test_label_index() {
+    let node_labels = vec!["a", "b", "c"];
+    let edge_labels = vec!["x", "y"];
+
+    let g: UnGraphMap<&str> = random_gnm_graph(30, 100, node_labels, edge_labels);
+    let g = UnStaticGraphConverter::new(g, true, true).convert();
+
+    let index = LabelIndex::new(&g, false);
+    let edge_index = LabelIndex::new(&g, true);
+    assert!(!index.is_by_edge_label());
+    assert!(edge_index.is_by_edge_label());
+
+    for v in g.node_indices() {
+        for label in 0..3 {
+            let expected: Vec<_> = g
+                .neighbors_iter(v)
+                .filter(|&n| g.get_labels()[n.id()] == label)
+                .collect();
+            assert_eq!(index.neighbors_by_label(v, label), &expected[..]);
+
+            for edge_label in 0..2 {
+                let expected: Vec<_> = expected
+                    .iter()
+                    .cloned()
+                    .filter(|&n| g.get_edge(v, n).get_label_id() == Some(edge_label))
+                    .collect();
+                assert_eq!(
+                    edge_index.neighbors_by_labels(v, label, edge_label),
+                    &expected[..]
+                );
+            }
+        }
+    }
+
+    // The in-edges, with an unlabeled node.
+    let edge_vec = EdgeVec::new(vec![0, 2, 2, 3], vec![1, 2, 1]);
+    let in_edge_vec = EdgeVec::new(vec![0, 0, 2, 3], vec![0, 2, 0]);
+    let g = DiStaticGraph::<&str>::with_labels(
+        3,
+        edge_vec,
+        Some(in_edge_vec),
+        vec![0, 1, DefaultId::max_value()],
+        SetMap::from_vec(vec!["a", "b"]),
+        SetMap::new(),
+    );
+
+    let index = LabelIndex::from_edge_vec(
+        g.get_in_edge_vec().as_ref().unwrap(),
+        g.get_labels(),
+        2,
+        None,
+    );
+    assert_eq!(index.neighbors_by_label(1, 0), &[0]);
+    assert_eq!(index.neighbors_by_label(1, 1), &[] as &[DefaultId]);
+    assert_eq!(index.neighbors_by_label(1, DefaultId::max_value()), &[2]);
+
+    // By edge labels, though the graph has none.
+    let index = LabelIndex::new(&g, true);
+    assert!(index.is_by_edge_label());
+    assert_eq!(
+        index.neighbors_by_labels(0, 1, DefaultId::max_value()),
+        &[1]
+    );
+
+    // The index only stores the non-empty groups, so many labels are fine.
+    let edge_vec = EdgeVec::with_labels(vec![0, 2, 2, 3], vec![1, 2, 0], vec![7, 9, 7]);
+    let index = LabelIndex::from_edge_vec(&edge_vec, &[0, 5, 5], 1 << 20, Some(1 << 20));
+    assert_eq!(index.neighbors_by_label(0, 5), &[1, 2]);
+    assert_eq!(index.neighbors_by_labels(0, 5, 7), &[1]);
+    assert_eq!(index.neighbors_by_labels(0, 5, 8), &[] as &[DefaultId]);
+    assert_eq!(index.neighbors_by_label(1, 5), &[] as &[DefaultId]);
+    assert_eq!(index.neighbors_by_labels(2, 0, 7), &[0]);
+}